@@ -0,0 +1,219 @@
+use crate::{decode_stream, encode_stream};
+use bitvec::prelude::*;
+
+/** Bytes consumed by a frame's header: a big-endian `u16` payload length in bits, followed by a
+big-endian `u16` count of measurements the payload decodes to. */
+pub const FRAME_HEADER_BYTES: usize = 4;
+/// Bytes consumed by a frame's trailing checksum.
+pub const FRAME_CRC_BYTES: usize = 1;
+
+/** Computes the CRC-8 (poly `0x07`, initial value `0`, no reflection- the common "CRC-8/SMBUS"
+parameters) over `data`, used to detect a corrupted frame.
+
+[`frame_encode`]/[`frame_decode`] are the only callers; it's kept private since nothing else in
+this crate needs a standalone checksum.
+
+[`frame_encode`]: ./fn.frame_encode.html
+[`frame_decode`]: ./fn.frame_decode.html
+*/
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+/** Why [`frame_decode`] rejected a frame, rather than risk handing a caller garbage measurements
+via an `unwrap_or_default`-style fallback.
+
+[`frame_decode`]: ./fn.frame_decode.html
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FrameError {
+    /// `data` doesn't (yet) hold as many bytes as the frame claims to need. A receiver on a
+    /// streaming link should keep buffering and retry once more bytes arrive.
+    Truncated,
+    /// The trailing CRC-8 byte didn't match the header+payload bytes actually received; the
+    /// frame was corrupted in transit and its payload cannot be trusted.
+    ChecksumMismatch,
+}
+
+/** Encodes `values` (via [`encode_stream`]) into a single self-delimiting frame written to
+`out`: a [`FRAME_HEADER_BYTES`]-byte header (the payload length in bits, then the number of
+measurements encoded, both big-endian `u16`s), the compressed payload bytes, and a trailing
+[`FRAME_CRC_BYTES`]-byte CRC-8 over the header and payload.
+
+Returns the suffix of `values` that didn't fit (because `out` ran out of room, mirroring
+[`encode_stream`]) and the number of bytes of `out` the frame actually occupies- which may be
+less than `out.len()` even on a full encode, since the frame is only as long as its payload
+requires.
+
+[`encode_stream`]: ./fn.encode_stream.html
+[`FRAME_HEADER_BYTES`]: ./constant.FRAME_HEADER_BYTES.html
+[`FRAME_CRC_BYTES`]: ./constant.FRAME_CRC_BYTES.html
+*/
+pub fn frame_encode<'a>(values: &'a [i16], out: &mut [u8]) -> (&'a [i16], usize) {
+    if out.len() < FRAME_HEADER_BYTES + FRAME_CRC_BYTES {
+        return (values, 0);
+    }
+
+    let payload_max = out.len() - FRAME_HEADER_BYTES - FRAME_CRC_BYTES;
+    let payload_buf = &mut out[FRAME_HEADER_BYTES..FRAME_HEADER_BYTES + payload_max];
+    payload_buf.fill(0);
+
+    let (bits_written, count) = {
+        let (_, buf_slice) = BitSlice::<Msb0, u8>::from_slice_mut(payload_buf).split_at_mut(0);
+        let (remaining, stream, _) = encode_stream(values, buf_slice);
+        (stream.len(), values.len() - remaining.len())
+    };
+
+    let payload_bytes = (bits_written + 7) / 8;
+    let crc_offset = FRAME_HEADER_BYTES + payload_bytes;
+    let frame_len = crc_offset + FRAME_CRC_BYTES;
+
+    out[0..2].copy_from_slice(&(bits_written as u16).to_be_bytes());
+    out[2..4].copy_from_slice(&(count as u16).to_be_bytes());
+    let crc = crc8(&out[..crc_offset]);
+    out[crc_offset] = crc;
+
+    (&values[count..], frame_len)
+}
+
+/** The inverse of [`frame_encode`]: validates a frame's CRC-8 before trusting its header, then
+decodes its payload via [`decode_stream`].
+
+Returns the suffix of `data` after this frame (so a transport can keep slicing off frames from a
+larger buffer), the measurements [`decode_stream`] produced, and the still-unused suffix of
+`values` (mirroring [`decode_stream`]'s own return shape).
+
+# Errors
+
+* [`FrameError::Truncated`]: `data` doesn't yet hold a complete frame- either shorter than a bare
+  header, or the header's claimed payload length runs past the end of `data`. A streaming
+  receiver should buffer more bytes and retry; this is not necessarily a corrupt frame.
+* [`FrameError::ChecksumMismatch`]: `data` holds a complete frame, but the trailing CRC-8 doesn't
+  match the header+payload bytes, so the frame was corrupted in transit. The payload is not
+  decoded in this case.
+
+[`frame_encode`]: ./fn.frame_encode.html
+[`decode_stream`]: ./fn.decode_stream.html
+[`FrameError::Truncated`]: ./enum.FrameError.html#variant.Truncated
+[`FrameError::ChecksumMismatch`]: ./enum.FrameError.html#variant.ChecksumMismatch
+*/
+pub fn frame_decode<'a, 'b>(
+    data: &'a [u8],
+    values: &'b mut [i16],
+) -> Result<(&'a [u8], &'b [i16], &'b mut [i16]), FrameError> {
+    if data.len() < FRAME_HEADER_BYTES + FRAME_CRC_BYTES {
+        return Err(FrameError::Truncated);
+    }
+
+    let bits_len = u16::from_be_bytes([data[0], data[1]]) as usize;
+    let payload_bytes = (bits_len + 7) / 8;
+    let crc_offset = FRAME_HEADER_BYTES + payload_bytes;
+    let frame_len = crc_offset + FRAME_CRC_BYTES;
+
+    if data.len() < frame_len {
+        return Err(FrameError::Truncated);
+    }
+
+    if crc8(&data[..crc_offset]) != data[crc_offset] {
+        return Err(FrameError::ChecksumMismatch);
+    }
+
+    let payload = &data[FRAME_HEADER_BYTES..crc_offset];
+    let bits = &BitSlice::<Msb0, u8>::from_slice(payload)[..bits_len];
+    let (_, decoded, unused) = decode_stream(bits, values);
+
+    Ok((&data[frame_len..], decoded, unused))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{frame_decode, frame_encode, FrameError, FRAME_CRC_BYTES, FRAME_HEADER_BYTES};
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let values = [1500, 1501, 1000, 999, 500, 500];
+        let mut out = [0u8; 64];
+        let (unencoded, frame_len) = frame_encode(&values, &mut out);
+        assert!(unencoded.is_empty());
+
+        let mut decoded = [0i16; 6];
+        let (rest, produced, empty) = frame_decode(&out[..frame_len], &mut decoded).unwrap();
+        assert!(rest.is_empty());
+        assert!(empty.is_empty());
+        assert_eq!(produced, values);
+    }
+
+    #[test]
+    fn header_records_bit_length_and_count() {
+        let values = [1500, 1501];
+        let mut out = [0u8; 64];
+        let (_, frame_len) = frame_encode(&values, &mut out);
+
+        let bits_len = u16::from_be_bytes([out[0], out[1]]) as usize;
+        let count = u16::from_be_bytes([out[2], out[3]]);
+        assert_eq!(count, 2);
+        // item (15 bits) + incr (3 bits)
+        assert_eq!(bits_len, 18);
+        assert_eq!(frame_len, FRAME_HEADER_BYTES + (bits_len + 7) / 8 + FRAME_CRC_BYTES);
+    }
+
+    #[test]
+    fn decode_rejects_flipped_bit() {
+        let values = [1500, 1501, 1000];
+        let mut out = [0u8; 64];
+        let (_, frame_len) = frame_encode(&values, &mut out);
+
+        out[FRAME_HEADER_BYTES] ^= 0x01;
+
+        let mut decoded = [0i16; 3];
+        assert_eq!(
+            frame_decode(&out[..frame_len], &mut decoded),
+            Err(FrameError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn decode_reports_truncated_frame() {
+        let values = [1500, 1501, 1000];
+        let mut out = [0u8; 64];
+        let (_, frame_len) = frame_encode(&values, &mut out);
+
+        let mut decoded = [0i16; 3];
+        assert_eq!(
+            frame_decode(&out[..frame_len - 1], &mut decoded),
+            Err(FrameError::Truncated)
+        );
+    }
+
+    #[test]
+    fn multiple_frames_back_to_back() {
+        let first = [1500, 1501];
+        let second = [-5, -5, -6];
+        let mut out = [0u8; 64];
+
+        let (_, first_len) = frame_encode(&first, &mut out);
+        let (_, second_len) = frame_encode(&second, &mut out[first_len..]);
+
+        let mut decoded = [0i16; 2];
+        let (rest, produced, _) = frame_decode(&out[..first_len + second_len], &mut decoded).unwrap();
+        assert_eq!(produced, first);
+
+        let mut decoded = [0i16; 3];
+        let (rest, produced, _) = frame_decode(rest, &mut decoded).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(produced, second);
+    }
+}
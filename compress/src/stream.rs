@@ -0,0 +1,271 @@
+use crate::{compress_entry, EntryType, Opcode};
+use bitvec::prelude::*;
+
+/** A stateful encoder that carries the delta-coding chain (the previous measurement) and the
+output bit cursor across repeated [`push`] calls.
+
+The free [`encode_stream`] function restarts the delta chain (forcing the next measurement into
+a full 15-bit [`Opcode::Item`]) on every call, which wastes the compression it's meant to provide
+when a sensor produces measurements continuously and callers only ever see fixed-size chunks of
+them. `StreamEncoder` instead keeps the chain alive across calls, so only the very first
+measurement ever pushed costs a 15-bit `Item`- everything after that is a cheap `Zero`/`Incr`/
+`Decr` (or worst case a 15-bit `Diff`) regardless of where chunk boundaries fall.
+
+[`push`]: ./struct.StreamEncoder.html#method.push
+[`encode_stream`]: ../fn.encode_stream.html
+[`Opcode::Item`]: ../enum.Opcode.html#variant.Item
+*/
+pub struct StreamEncoder<'b> {
+    last: Option<i16>,
+    buf: &'b mut BitSlice<Msb0, <u8 as BitStore>::Alias>,
+    cursor: usize,
+}
+
+impl<'b> StreamEncoder<'b> {
+    /** Creates a `StreamEncoder` writing into `buf`, with no delta context yet (the first
+    measurement pushed is therefore encoded as an absolute [`Opcode::Item`]).
+
+    [`Opcode::Item`]: ../enum.Opcode.html#variant.Item
+    */
+    pub fn new(buf: &'b mut BitSlice<Msb0, <u8 as BitStore>::Alias>) -> Self {
+        StreamEncoder {
+            last: None,
+            buf,
+            cursor: 0,
+        }
+    }
+
+    /** Encodes a prefix of `values` into the backing buffer, continuing the delta chain from
+    whatever was last pushed (across this and any earlier `push` call on this encoder). Stops as
+    soon as the next entry wouldn't fit in the buffer's remaining capacity, leaving the rest of
+    `values` for a later call. Returns the number of values consumed. */
+    pub fn push(&mut self, values: &[i16]) -> usize {
+        let mut consumed = 0;
+
+        for &next in values {
+            let buf_len = self.buf.len();
+
+            if buf_len <= self.cursor + 15 {
+                break;
+            }
+
+            let entry = match self.last {
+                None => EntryType::Absolute(next),
+                Some(last) => EntryType::Diff((next, last)),
+            };
+            let (bits, pkt) = compress_entry(entry);
+            self.buf[self.cursor..][..bits].clone_from_bitslice(&pkt[..bits]);
+
+            self.last = Some(next);
+            self.cursor += bits;
+            consumed += 1;
+        }
+
+        consumed
+    }
+
+    /** Consumes the encoder, flushing any partially-filled trailing byte (its unused bits are
+    already zero) and returning the bits written so far, along with the still-unused remainder of
+    the backing buffer. */
+    #[allow(clippy::type_complexity)]
+    pub fn finish(
+        self,
+    ) -> (
+        &'b BitSlice<Msb0, <u8 as BitStore>::Alias>,
+        &'b mut BitSlice<Msb0, <u8 as BitStore>::Alias>,
+    ) {
+        let (written, rest) = self.buf.split_at_mut(self.cursor);
+        (&*written, rest)
+    }
+}
+
+/** The inverse of [`StreamEncoder`]: a stateful decoder that carries the delta-coding chain and
+the input bit cursor across repeated [`push`] calls, so a bitstream produced across many
+[`StreamEncoder::push`] calls can be decoded back in arbitrary chunks too.
+
+[`push`]: ./struct.StreamDecoder.html#method.push
+[`StreamEncoder`]: ./struct.StreamEncoder.html
+[`StreamEncoder::push`]: ./struct.StreamEncoder.html#method.push
+*/
+pub struct StreamDecoder<'a> {
+    last: Option<i16>,
+    data: &'a BitSlice<Msb0, u8>,
+    cursor: usize,
+}
+
+impl<'a> StreamDecoder<'a> {
+    /// Creates a `StreamDecoder` reading from `data`, with no delta context yet.
+    pub fn new(data: &'a BitSlice<Msb0, u8>) -> Self {
+        StreamDecoder {
+            last: None,
+            data,
+            cursor: 0,
+        }
+    }
+
+    /** Decodes measurements into a prefix of `values`, continuing the delta chain from whatever
+    was last produced (across this and any earlier `push` call on this decoder). Stops as soon as
+    there isn't enough remaining input to decode the next entry, leaving the rest of `values`
+    unfilled. Returns the number of values produced. */
+    pub fn push(&mut self, values: &mut [i16]) -> usize {
+        let mut produced = 0;
+
+        for slot in values.iter_mut() {
+            let data_len = self.data.len();
+
+            if data_len <= self.cursor {
+                break;
+            }
+
+            if !self.data[self.cursor] {
+                // A repeat of the previous value, not a gap- `last` must survive so the entry
+                // after this one still has the right delta context.
+                *slot = self.last.unwrap_or_default();
+                self.cursor += 1;
+                produced += 1;
+                continue;
+            }
+
+            // The remaining bits must cover whichever opcode this turns out to be: 3 bits for
+            // `Incr`/`Decr`, 15 for `Item`/`Diff`- not a flat 15, which would wrongly refuse a
+            // 3-bit opcode that starts within the last 15 bits of the stream.
+            let remaining = data_len - self.cursor;
+            if remaining < 3 {
+                break;
+            }
+
+            let opcode_bits = &self.data[self.cursor..self.cursor + 3];
+            let opcode: Opcode = opcode_bits.load_be::<u8>().into();
+            let needed_bits = match opcode {
+                Opcode::Incr | Opcode::Decr => 3,
+                Opcode::Item | Opcode::Diff => 15,
+                Opcode::Zero => unreachable!("Handled earlier"),
+            };
+            if remaining < needed_bits {
+                break;
+            }
+
+            match opcode {
+                Opcode::Incr => {
+                    let mut prev = self.last.take().unwrap_or_default();
+                    prev += 1;
+                    self.last = Some(prev);
+                    *slot = prev;
+                    self.cursor += 3;
+                }
+                Opcode::Decr => {
+                    let mut prev = self.last.take().unwrap_or_default();
+                    prev -= 1;
+                    self.last = Some(prev);
+                    *slot = prev;
+                    self.cursor += 3;
+                }
+                Opcode::Item => {
+                    let entry = &self.data[self.cursor..self.cursor + 15];
+                    let val = if entry[3] {
+                        (entry[3..15].load_be::<u16>() as i16) - 0x1000
+                    } else {
+                        entry[3..15].load_be::<u16>() as i16
+                    };
+
+                    self.last = Some(val);
+                    *slot = val;
+                    self.cursor += 15;
+                }
+                Opcode::Diff => {
+                    let entry = &self.data[self.cursor..self.cursor + 15];
+                    let diff = if entry[3] {
+                        (entry[3..15].load_be::<u16>() as i16) - 0x1000
+                    } else {
+                        entry[3..15].load_be::<u16>() as i16
+                    };
+
+                    let prev = self.last.take().unwrap_or_default();
+                    let next = prev + diff;
+                    self.last = Some(next);
+                    *slot = next;
+                    self.cursor += 15;
+                }
+                Opcode::Zero => unreachable!("Handled earlier"),
+            }
+
+            produced += 1;
+        }
+
+        produced
+    }
+
+    /// Consumes the decoder, returning the still-unconsumed remainder of the backing bitstream.
+    pub fn finish(self) -> &'a BitSlice<Msb0, u8> {
+        &self.data[self.cursor..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamDecoder, StreamEncoder};
+    use bitvec::prelude::*;
+
+    #[test]
+    fn encode_across_pushes_continues_delta_chain() {
+        let mut buf = bitarr![Msb0, u8; 0; 256];
+        let (_, buf_slice) = buf.as_mut_bitslice().split_at_mut(0);
+        let mut enc = StreamEncoder::new(buf_slice);
+
+        // First push sees no prior context, so 1500 costs a full Item.
+        assert_eq!(enc.push(&[1500]), 1);
+        // Second push is a separate call, but the chain survives: 1501 is a cheap Incr, not
+        // another Item.
+        assert_eq!(enc.push(&[1501]), 1);
+
+        let (stream, _) = enc.finish();
+        assert_eq!(
+            stream,
+            bits![Msb0, u8;
+                // item: 1500
+                1, 1, 0, /**/ 0, 1, 0, 1, /**/ 1, 1, 0, 1, /**/ 1, 1, 0, 0,
+                // incr
+                1, 0, 0,
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_across_pushes_continues_delta_chain() {
+        let bits = bits![Msb0, u8;
+            // item: 1500
+            1, 1, 0, /**/ 0, 1, 0, 1, /**/ 1, 1, 0, 1, /**/ 1, 1, 0, 0,
+            // incr
+            1, 0, 0,
+            // decr
+            1, 0, 1,
+        ];
+        let mut dec = StreamDecoder::new(bits);
+
+        let mut first = [0i16; 1];
+        assert_eq!(dec.push(&mut first), 1);
+        assert_eq!(first, [1500]);
+
+        // A later, separate push() call still has the 1500 as delta context.
+        let mut rest = [0i16; 2];
+        assert_eq!(dec.push(&mut rest), 2);
+        assert_eq!(rest, [1501, 1500]);
+
+        assert!(dec.finish().is_empty());
+    }
+
+    #[test]
+    fn push_stops_when_buf_or_data_runs_out() {
+        let mut buf = bitarr![Msb0, u8; 0; 16];
+        let (_, buf_slice) = buf.as_mut_bitslice().split_at_mut(0);
+        let mut enc = StreamEncoder::new(buf_slice);
+
+        // The single Item for 1500 exactly fills the 16-bit buffer (15 bits of payload plus 1
+        // bit unused), so a second value has no room left this call.
+        assert_eq!(enc.push(&[1500, 1501]), 1);
+
+        let (stream, unused) = enc.finish();
+        assert_eq!(stream.len(), 15);
+        assert_eq!(unused.len(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use fixed::types::I8F8;
+use serde::Serialize;
+use tcn75a::Limits;
+
+/// Software hysteresis comparator mirroring the TCN75A's own ALERT pin semantics: the alert
+/// becomes asserted once the temperature rises above the Limit-Set (high) value, and stays
+/// asserted until it falls back below the Hysteresis (low) value.
+pub struct AlertMonitor {
+    limits: Option<Limits>,
+    asserted: bool,
+}
+
+impl AlertMonitor {
+    pub fn new(limits: Option<Limits>) -> Self {
+        AlertMonitor {
+            limits,
+            asserted: false,
+        }
+    }
+
+    pub fn limits(&self) -> Option<Limits> {
+        self.limits
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = Some(limits);
+    }
+
+    pub fn asserted(&self) -> bool {
+        self.asserted
+    }
+
+    /// Evaluates a new temperature reading against the configured limits, updating and
+    /// returning the current alert state. A no-op (alert stays deasserted) if no limits have
+    /// been configured yet.
+    pub fn update(&mut self, temp: I8F8) -> bool {
+        if let Some(limits) = self.limits {
+            let (lo, hi): (I8F8, I8F8) = limits.into();
+
+            if !self.asserted && temp > hi {
+                self.asserted = true;
+            } else if self.asserted && temp < lo {
+                self.asserted = false;
+            }
+        }
+
+        self.asserted
+    }
+}
+
+/// JSON representation of the current alert state, served from `GET /alert`.
+#[derive(Serialize)]
+pub struct AlertStatus {
+    pub asserted: bool,
+    pub limit_low: Option<I8F8>,
+    pub limit_high: Option<I8F8>,
+}
+
+impl From<&AlertMonitor> for AlertStatus {
+    fn from(monitor: &AlertMonitor) -> Self {
+        let (limit_low, limit_high) = match monitor.limits() {
+            Some(limits) => {
+                let (lo, hi) = limits.into();
+                (Some(lo), Some(hi))
+            }
+            None => (None, None),
+        };
+
+        AlertStatus {
+            asserted: monitor.asserted(),
+            limit_low,
+            limit_high,
+        }
+    }
+}
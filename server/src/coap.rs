@@ -0,0 +1,226 @@
+//! CoAP resource-handler glue for [`SampleBuf`], built on the `coap-handler`/`coap-message`
+//! traits instead of `hyper`- so, given this crate is literally an "i2c-server," the buffer is
+//! queryable as a real [`embedded-nal`]-style network resource, not just something serializable
+//! in-process or over plain HTTP.
+//!
+//! Two resources are served, mirroring `main.rs`'s `GET /`/`GET /alert` HTTP handlers:
+//!
+//! * `GET /temperature`: the current reading, as an [`I8F8`].
+//! * `GET /samples`: the same `timestamp`/`sample_rate`/base64 `buf` structure `GET /` already
+//!   serves over HTTP.
+//!
+//! Both support CoAP content-format negotiation (CBOR or the existing JSON shape, selected by
+//! the request's Accept option). A request that registers for observation gets an Observe option
+//! carrying the buffer's [`generation`](SampleBuf::generation) in its response; no notifications
+//! are pushed to registered observers afterwards, since `main.rs`'s CoAP thread only answers
+//! requests as they arrive.
+//!
+//! [`embedded-nal`]: ../embedded_nal/index.html
+
+use std::sync::Arc;
+
+use coap_handler::Handler;
+use coap_message::{MessageOption, MutableWritableMessage, ReadableMessage};
+use fixed::types::I8F8;
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::samples::SampleBuf;
+
+/// CoAP content-format numbers this module negotiates between (see the [IANA CoAP Content-Formats
+/// registry]).
+///
+/// [IANA CoAP Content-Formats registry]: https://www.iana.org/assignments/core-parameters/core-parameters.xhtml#content-formats
+mod content_format {
+    pub const JSON: u16 = 50;
+    pub const CBOR: u16 = 60;
+}
+
+/// Which representation a request asked for (via its Accept option), resolved up front in
+/// [`CoapSamples::extract_request_data`] so [`build_response`](CoapSamples::build_response)
+/// doesn't need to re-inspect the request's options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Representation {
+    /// Same shape `GET /`/`GET /alert` already serve over HTTP.
+    Json,
+    Cbor,
+}
+
+impl Representation {
+    /// JSON is the fallback when a client's Accept option is absent or names a format this
+    /// module doesn't recognize, matching the plain-HTTP endpoints, which only ever speak JSON.
+    fn from_accept(accept: Option<u16>) -> Representation {
+        match accept {
+            Some(content_format::CBOR) => Representation::Cbor,
+            _ => Representation::Json,
+        }
+    }
+
+    fn content_format(self) -> u16 {
+        match self {
+            Representation::Json => content_format::JSON,
+            Representation::Cbor => content_format::CBOR,
+        }
+    }
+
+    /// Serializes `body` in the negotiated representation.
+    fn encode<T: Serialize>(self, body: &T) -> Result<Vec<u8>, String> {
+        match self {
+            Representation::Json => serde_json::to_vec(body).map_err(|e| e.to_string()),
+            Representation::Cbor => serde_cbor::to_vec(body).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// The resources this handler serves, resolved from the request's Uri-Path options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resource {
+    Temperature,
+    Samples,
+    NotFound,
+}
+
+impl Resource {
+    fn from_path_segments<'a>(segments: impl Iterator<Item = &'a [u8]>) -> Resource {
+        let segments: Vec<&[u8]> = segments.collect();
+        match segments.as_slice() {
+            [b"temperature"] => Resource::Temperature,
+            [b"samples"] => Resource::Samples,
+            _ => Resource::NotFound,
+        }
+    }
+}
+
+/// Body for `GET /temperature`, serialized in whichever [`Representation`] the request asked for.
+#[derive(Serialize)]
+struct TemperatureBody {
+    temperature: I8F8,
+}
+
+/// Everything [`CoapSamples::build_response`] needs, captured once by
+/// [`extract_request_data`](CoapSamples::extract_request_data) so the [`SampleBuf`] lock is only
+/// taken while actually rendering a response, not for the whole CoAP exchange.
+struct SampleRequest {
+    resource: Resource,
+    representation: Representation,
+    /// Whether the request carried an Observe option (value `0`, "register"); a non-zero value
+    /// (deregister) is treated the same as "absent" here, since this handler has no per-client
+    /// observer list to remove from- see [`CoapSamples`]'s doc comment.
+    observe: bool,
+}
+
+/** Serves [`SampleBuf`]'s buffer and the current reading over CoAP.
+
+There is no per-client subscriber table. [`should_notify`](CoapSamples::should_notify) only
+remembers the [`generation`](SampleBuf::generation) it was last given and reports whether it has
+moved since, so a single post that pads in several intervals (see [`SampleBuf::post`]) counts as
+one change. The CoAP server loop in `main.rs` does not call it and sends no notifications; it only
+answers requests.
+*/
+pub struct CoapSamples {
+    samples: Arc<Mutex<SampleBuf<i16>>>,
+    last_notified_generation: Option<u64>,
+}
+
+impl CoapSamples {
+    pub fn new(samples: Arc<Mutex<SampleBuf<i16>>>) -> Self {
+        CoapSamples {
+            samples,
+            last_notified_generation: None,
+        }
+    }
+
+    /// Whether the buffer has posted a new sample since the last call that returned `true`
+    /// (always `true` on the first call).
+    pub fn should_notify(&mut self, current_generation: u64) -> bool {
+        let changed = self.last_notified_generation != Some(current_generation);
+        if changed {
+            self.last_notified_generation = Some(current_generation);
+        }
+        changed
+    }
+
+    fn render(buf: &SampleBuf<i16>, resource: Resource, representation: Representation) -> Result<Vec<u8>, String> {
+        match resource {
+            Resource::Temperature => {
+                let raw = buf.last().copied().ok_or("no sample posted yet")?;
+                representation.encode(&TemperatureBody { temperature: crate::raw_to_i8f8(raw) })
+            }
+            // `SampleBuf`'s own `Serialize` impl already produces the `timestamp`/`sample_rate`/
+            // base64 `buf` shape `GET /` serves over HTTP; CBOR just packs the same fields.
+            Resource::Samples => representation.encode(buf),
+            Resource::NotFound => Err("not found".to_string()),
+        }
+    }
+}
+
+impl Handler for CoapSamples {
+    type RequestData = SampleRequest;
+
+    fn extract_request_data(&mut self, request: &impl ReadableMessage) -> Self::RequestData {
+        let resource = Resource::from_path_segments(
+            request
+                .options()
+                .filter(|o| o.number() == coap_numbers::option::URI_PATH)
+                .map(|o| o.value()),
+        );
+
+        let accept = request
+            .options()
+            .find(|o| o.number() == coap_numbers::option::ACCEPT)
+            .and_then(|o| o.value().first().copied())
+            .map(u16::from);
+
+        let observe = request
+            .options()
+            .find(|o| o.number() == coap_numbers::option::OBSERVE)
+            .map_or(false, |o| o.value().iter().all(|&b| b == 0));
+
+        SampleRequest {
+            resource,
+            representation: Representation::from_accept(accept),
+            observe,
+        }
+    }
+
+    fn estimate_length(&mut self, request: &Self::RequestData) -> usize {
+        match request.resource {
+            // A size hint, not a bound: a full 86400-sample buffer base64'd in Raw mode is about
+            // 230 KB, far more than this.
+            Resource::Samples => 8192,
+            Resource::Temperature | Resource::NotFound => 64,
+        }
+    }
+
+    fn build_response(&mut self, response: &mut impl MutableWritableMessage, request: Self::RequestData) {
+        // `Handler::build_response` is synchronous (`coap-handler`'s contract targets `no_std`/
+        // blocking CoAP backends), so this runs on the dedicated CoAP server thread `main.rs`
+        // spawns rather than the tokio reactor- `blocking_lock` never contends with an await
+        // point on the async HTTP path.
+        let buf = self.samples.blocking_lock();
+
+        match Self::render(&buf, request.resource, request.representation) {
+            Ok(payload) => {
+                response.set_code(coap_numbers::code::CONTENT);
+                // Options must be added in ascending option-number order (`MutableWritableMessage`
+                // delta-codes them against the previous one), so Observe (6) comes before
+                // Content-Format (12).
+                if request.observe {
+                    // RFC 7641 §3.2 caps the Observe value at 3 bytes (24 bits); truncate the
+                    // generation counter to its low 24 bits rather than sending all 8.
+                    let sequence = (buf.generation() & 0x00FF_FFFF) as u32;
+                    response.add_option(coap_numbers::option::OBSERVE, &sequence.to_be_bytes()[1..]);
+                }
+                response
+                    .add_option(coap_numbers::option::CONTENT_FORMAT, &request.representation.content_format().to_be_bytes());
+                response.set_payload(&payload);
+            }
+            Err(_) if request.resource == Resource::NotFound => {
+                response.set_code(coap_numbers::code::NOT_FOUND);
+            }
+            Err(_) => {
+                response.set_code(coap_numbers::code::SERVICE_UNAVAILABLE);
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Settings for the I2C server, loaded from a `key=value`-per-line config file and/or
+/// merged with the equivalent `clap` flags.
+///
+/// Every field is optional because a `Config` built from the config file and a `Config` built
+/// from CLI args are combined with [`Config::merge`] before the server uses them; CLI-supplied
+/// values always win over the file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Config {
+    pub bind: Option<String>,
+    pub sample_rate: Option<u8>,
+    pub node: Option<String>,
+    pub addr: Option<u16>,
+    pub device: Option<String>,
+    pub limit_low: Option<String>,
+    pub limit_high: Option<String>,
+    pub tau_ms: Option<u32>,
+}
+
+impl Config {
+    /// Parses a `key=value`-per-line config file. Unknown keys and blank/`#`-commented lines
+    /// are ignored. A malformed value (e.g. a non-numeric `sample_rate`) is silently dropped,
+    /// leaving the field unset, rather than failing the whole file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Config> {
+        let contents = fs::read_to_string(path)?;
+        let mut cfg = Config::default();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = match parts.next() {
+                Some(v) => v.trim(),
+                None => continue,
+            };
+
+            match key {
+                "bind" => cfg.bind = Some(value.to_string()),
+                "sample_rate" => cfg.sample_rate = value.parse().ok(),
+                "node" => cfg.node = Some(value.to_string()),
+                "addr" => cfg.addr = u16::from_str_radix(value.trim_start_matches("0x"), 16).ok(),
+                "device" => cfg.device = Some(value.to_string()),
+                "limit_low" => cfg.limit_low = Some(value.to_string()),
+                "limit_high" => cfg.limit_high = Some(value.to_string()),
+                "tau_ms" => cfg.tau_ms = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Ok(cfg)
+    }
+
+    /// Overlays `other` on top of `self`, preferring `other`'s values wherever it has one.
+    /// Intended usage is `file_cfg.merge(cli_cfg)`, so CLI args win over the config file.
+    pub fn merge(self, other: Config) -> Config {
+        Config {
+            bind: other.bind.or(self.bind),
+            sample_rate: other.sample_rate.or(self.sample_rate),
+            node: other.node.or(self.node),
+            addr: other.addr.or(self.addr),
+            device: other.device.or(self.device),
+            limit_low: other.limit_low.or(self.limit_low),
+            limit_high: other.limit_high.or(self.limit_high),
+            tau_ms: other.tau_ms.or(self.tau_ms),
+        }
+    }
+}
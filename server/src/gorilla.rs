@@ -0,0 +1,408 @@
+use std::mem::size_of;
+
+use bitvec::prelude::*;
+
+/// Number of bits needed to represent any value in `0..=max_inclusive`.
+fn bits_for(max_inclusive: usize) -> usize {
+    let mut bits = 0;
+    while (1usize << bits) <= max_inclusive {
+        bits += 1;
+    }
+    bits
+}
+
+fn mask(bits: usize) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    }
+}
+
+fn write_bits(out: &mut BitVec<Msb0, u8>, value: u128, bits: usize) {
+    for i in (0..bits).rev() {
+        out.push((value >> i) & 1 == 1);
+    }
+}
+
+fn next_bit(bits: &BitSlice<Msb0, u8>, cursor: &mut usize) -> Option<bool> {
+    let bit = *bits.get(*cursor)?;
+    *cursor += 1;
+    Some(bit)
+}
+
+fn read_bits(bits: &BitSlice<Msb0, u8>, cursor: &mut usize, count: usize) -> Option<u128> {
+    if bits.len() < *cursor + count {
+        return None;
+    }
+
+    let mut value = 0u128;
+    for i in 0..count {
+        value = (value << 1) | u128::from(bits[*cursor + i]);
+    }
+    *cursor += count;
+    Some(value)
+}
+
+fn zigzag_encode(v: i64) -> u128 {
+    (((v as i128) << 1) ^ ((v as i128) >> 63)) as u128
+}
+
+fn zigzag_decode(v: u128) -> i64 {
+    (((v >> 1) as i64) ^ -((v & 1) as i64)) as i64
+}
+
+/// The four increasing bit-width buckets a nonzero timestamp delta-of-delta is written in,
+/// selected by a `10`/`110`/`1110`/`1111` control prefix (a bare `0` is reserved for the D == 0
+/// case, handled separately by the caller).
+enum Bucket {
+    B7,
+    B9,
+    B12,
+    B64,
+}
+
+impl Bucket {
+    fn for_zigzag(z: u128) -> Bucket {
+        if z < (1 << 7) {
+            Bucket::B7
+        } else if z < (1 << 9) {
+            Bucket::B9
+        } else if z < (1 << 12) {
+            Bucket::B12
+        } else {
+            Bucket::B64
+        }
+    }
+
+    fn bits(&self) -> usize {
+        match self {
+            Bucket::B7 => 7,
+            Bucket::B9 => 9,
+            Bucket::B12 => 12,
+            Bucket::B64 => 64,
+        }
+    }
+
+    fn write_prefix(&self, out: &mut BitVec<Msb0, u8>) {
+        match self {
+            Bucket::B7 => out.extend([true, false]),
+            Bucket::B9 => out.extend([true, true, false]),
+            Bucket::B12 => out.extend([true, true, true, false]),
+            Bucket::B64 => out.extend([true, true, true, true]),
+        }
+    }
+}
+
+/** Gorilla-style delta-of-delta encoding of `timestamps` into `out`. `timestamps[0]` is written
+in full, `timestamps[1] - timestamps[0]` in full, and every later sample writes a single `0` bit
+when its delta-of-delta is exactly zero (the common case at a fixed sample rate), otherwise a
+control prefix (`10`, `110`, `1110`, `1111`) selecting an increasing bit-width bucket for the
+zig-zag-encoded delta-of-delta. */
+pub fn encode_timestamps(out: &mut BitVec<Msb0, u8>, timestamps: &[u64]) {
+    if timestamps.is_empty() {
+        return;
+    }
+
+    write_bits(out, u128::from(timestamps[0]), 64);
+    if timestamps.len() == 1 {
+        return;
+    }
+
+    let mut delta = timestamps[1].wrapping_sub(timestamps[0]) as i64;
+    write_bits(out, zigzag_encode(delta), 64);
+
+    for w in timestamps.windows(3) {
+        let next_delta = w[2].wrapping_sub(w[1]) as i64;
+        let dod = next_delta.wrapping_sub(delta);
+
+        if dod == 0 {
+            out.push(false);
+        } else {
+            let z = zigzag_encode(dod);
+            let bucket = Bucket::for_zigzag(z);
+            bucket.write_prefix(out);
+            write_bits(out, z & mask(bucket.bits()), bucket.bits());
+        }
+
+        delta = next_delta;
+    }
+}
+
+/// The inverse of [`encode_timestamps`]: decodes exactly `count` timestamps starting at
+/// `*cursor`, advancing it past the bits consumed. Returns `None` if `bits` runs out first.
+pub fn decode_timestamps(
+    bits: &BitSlice<Msb0, u8>,
+    cursor: &mut usize,
+    count: usize,
+) -> Option<Vec<u64>> {
+    let mut out = Vec::with_capacity(count);
+    if count == 0 {
+        return Some(out);
+    }
+
+    let t0 = read_bits(bits, cursor, 64)? as u64;
+    out.push(t0);
+    if count == 1 {
+        return Some(out);
+    }
+
+    let mut delta = zigzag_decode(read_bits(bits, cursor, 64)?);
+    out.push(t0.wrapping_add(delta as u64));
+
+    for _ in 2..count {
+        let dod = if !next_bit(bits, cursor)? {
+            0i64
+        } else if !next_bit(bits, cursor)? {
+            zigzag_decode(read_bits(bits, cursor, 7)?)
+        } else if !next_bit(bits, cursor)? {
+            zigzag_decode(read_bits(bits, cursor, 9)?)
+        } else if !next_bit(bits, cursor)? {
+            zigzag_decode(read_bits(bits, cursor, 12)?)
+        } else {
+            zigzag_decode(read_bits(bits, cursor, 64)?)
+        };
+
+        delta = delta.wrapping_add(dod);
+        let prev = *out.last().unwrap();
+        out.push(prev.wrapping_add(delta as u64));
+    }
+
+    Some(out)
+}
+
+/// Reinterprets `v`'s in-memory bytes as an unsigned integer, zero-extended into a `u128`, along
+/// with the number of meaningful low bits (`size_of::<T>() * 8`). Like [`SampleBuf`]'s existing
+/// raw-byte serializer, this doesn't attempt to canonicalize endianness- it only needs to be
+/// self-consistent between [`encode_values`] and [`decode_values`] on the same host.
+///
+/// [`SampleBuf`]: crate::samples::SampleBuf
+fn bit_pattern<T: Copy>(v: T) -> (u128, usize) {
+    let width = size_of::<T>();
+    let mut bytes = [0u8; 16];
+    unsafe {
+        std::ptr::copy_nonoverlapping(&v as *const T as *const u8, bytes.as_mut_ptr(), width);
+    }
+    (u128::from_ne_bytes(bytes), width * 8)
+}
+
+/// The inverse of [`bit_pattern`]: reconstructs a `T` from its zero-extended bit pattern.
+fn from_bit_pattern<T: Copy>(bits_val: u128, width_bytes: usize) -> T {
+    let bytes = bits_val.to_ne_bytes();
+    unsafe { std::ptr::read(bytes[..width_bytes].as_ptr() as *const T) }
+}
+
+/** XOR-codes `values` into `out`: the first sample is written in full; every later sample XORs
+its bit pattern against the previous sample's, writing a `0` bit when the XOR is zero, otherwise
+a `1` bit followed by a bit saying whether the previous sample's leading/trailing-zero window is
+reused (skipping the window header) and then just the meaningful bits, or- if the window
+changed- a fresh window header (leading-zero count, then meaningful-bit count) before the
+meaningful bits. */
+pub fn encode_values<T: Copy>(out: &mut BitVec<Msb0, u8>, values: &[T]) {
+    let mut prev: Option<u128> = None;
+    let mut window: Option<(usize, usize)> = None;
+
+    for &v in values {
+        let (bits_val, width_bits) = bit_pattern(v);
+
+        match prev {
+            None => write_bits(out, bits_val, width_bits),
+            Some(p) => {
+                let xor = bits_val ^ p;
+
+                if xor == 0 {
+                    out.push(false);
+                } else {
+                    out.push(true);
+
+                    let highest_set = 127 - xor.leading_zeros() as usize;
+                    let lz = width_bits - 1 - highest_set;
+                    let tz = xor.trailing_zeros() as usize;
+
+                    let reuse = window.map_or(false, |(wlz, wtz)| lz >= wlz && tz >= wtz);
+
+                    if reuse {
+                        let (wlz, wtz) = window.unwrap();
+                        out.push(false);
+                        let len = width_bits - wlz - wtz;
+                        write_bits(out, (xor >> wtz) & mask(len), len);
+                    } else {
+                        out.push(true);
+                        let header_bits = bits_for(width_bits.saturating_sub(1));
+                        let len = width_bits - lz - tz;
+                        write_bits(out, lz as u128, header_bits);
+                        write_bits(out, (len - 1) as u128, header_bits);
+                        write_bits(out, (xor >> tz) & mask(len), len);
+                        window = Some((lz, tz));
+                    }
+                }
+            }
+        }
+
+        prev = Some(bits_val);
+    }
+}
+
+/// The inverse of [`encode_values`]: decodes exactly `count` values of type `T` starting at
+/// `*cursor`, advancing it past the bits consumed. Returns `None` if `bits` runs out first.
+pub fn decode_values<T: Copy>(
+    bits: &BitSlice<Msb0, u8>,
+    cursor: &mut usize,
+    count: usize,
+) -> Option<Vec<T>> {
+    let width_bytes = size_of::<T>();
+    let width_bits = width_bytes * 8;
+    let header_bits = bits_for(width_bits.saturating_sub(1));
+
+    let mut out = Vec::with_capacity(count);
+    let mut prev: Option<u128> = None;
+    let mut window: Option<(usize, usize)> = None;
+
+    for _ in 0..count {
+        let bits_val = match prev {
+            None => read_bits(bits, cursor, width_bits)?,
+            Some(p) => {
+                if !next_bit(bits, cursor)? {
+                    p
+                } else if !next_bit(bits, cursor)? {
+                    let (wlz, wtz) = window?;
+                    let len = width_bits - wlz - wtz;
+                    let xor = read_bits(bits, cursor, len)? << wtz;
+                    p ^ xor
+                } else {
+                    let lz = read_bits(bits, cursor, header_bits)? as usize;
+                    let len = read_bits(bits, cursor, header_bits)? as usize + 1;
+                    let tz = width_bits - lz - len;
+                    let xor = read_bits(bits, cursor, len)? << tz;
+                    window = Some((lz, tz));
+                    p ^ xor
+                }
+            }
+        };
+
+        out.push(from_bit_pattern(bits_val, width_bytes));
+        prev = Some(bits_val);
+    }
+
+    Some(out)
+}
+
+/** Packs `timestamps` and `values` (same length) into a single self-contained byte buffer: a
+4-byte big-endian sample count, followed by the Gorilla-coded timestamp stream
+([`encode_timestamps`]) immediately followed by the Gorilla-coded value stream
+([`encode_values`]). Both streams decode to exactly `count` entries, so no boundary marker is
+needed between them. */
+pub fn encode<T: Copy>(timestamps: &[u64], values: &[T]) -> Vec<u8> {
+    let count = timestamps.len().min(values.len());
+
+    let mut bits: BitVec<Msb0, u8> = BitVec::new();
+    encode_timestamps(&mut bits, &timestamps[..count]);
+    encode_values(&mut bits, &values[..count]);
+
+    let mut out = Vec::with_capacity(4 + bits.as_raw_slice().len());
+    out.extend_from_slice(&(count as u32).to_be_bytes());
+    out.extend_from_slice(bits.as_raw_slice());
+    out
+}
+
+/// The inverse of [`encode`].
+pub fn decode<T: Copy>(data: &[u8]) -> Option<(Vec<u64>, Vec<T>)> {
+    if data.len() < 4 {
+        return None;
+    }
+
+    let count = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+    let bits = BitSlice::<Msb0, u8>::from_slice(&data[4..]);
+    let mut cursor = 0;
+
+    let timestamps = decode_timestamps(bits, &mut cursor, count)?;
+    let values = decode_values::<T>(bits, &mut cursor, count)?;
+
+    Some((timestamps, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_round_trip_fixed_rate() {
+        let timestamps: Vec<u64> = (0..20).map(|i| i * 1_000_000_000).collect();
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_timestamps(&mut bits, &timestamps);
+
+        let mut cursor = 0;
+        let decoded = decode_timestamps(&bits, &mut cursor, timestamps.len()).unwrap();
+        assert_eq!(decoded, timestamps);
+        assert_eq!(cursor, bits.len());
+    }
+
+    #[test]
+    fn fixed_rate_timestamps_are_almost_all_zero_bits() {
+        let timestamps: Vec<u64> = (0..100).map(|i| i * 1_000_000_000).collect();
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_timestamps(&mut bits, &timestamps);
+
+        // First value (64 bits) + first delta (64 bits) + one `0` bit per later sample.
+        assert_eq!(bits.len(), 64 + 64 + (timestamps.len() - 2));
+    }
+
+    #[test]
+    fn timestamps_round_trip_large_jitter() {
+        // A delta-of-delta whose zig-zag encoding needs more than 32 bits (~2.1s of jitter at
+        // nanosecond resolution) used to get silently truncated by the B32 bucket.
+        let timestamps = vec![0u64, 1_000_000_000, 1_000_000_000, 10_000_000_000_000];
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_timestamps(&mut bits, &timestamps);
+
+        let mut cursor = 0;
+        let decoded = decode_timestamps(&bits, &mut cursor, timestamps.len()).unwrap();
+        assert_eq!(decoded, timestamps);
+        assert_eq!(cursor, bits.len());
+    }
+
+    #[test]
+    fn timestamps_round_trip_irregular_gaps() {
+        let timestamps = vec![1_000u64, 1_500, 1_800, 5_000, 5_001, 9_999_999];
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_timestamps(&mut bits, &timestamps);
+
+        let mut cursor = 0;
+        let decoded = decode_timestamps(&bits, &mut cursor, timestamps.len()).unwrap();
+        assert_eq!(decoded, timestamps);
+    }
+
+    #[test]
+    fn values_round_trip_i16() {
+        let values: [i16; 8] = [1500, 1500, 1501, 1501, 1501, -5, -5, 1234];
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_values(&mut bits, &values);
+
+        let mut cursor = 0;
+        let decoded: Vec<i16> = decode_values(&bits, &mut cursor, values.len()).unwrap();
+        assert_eq!(decoded, values);
+        assert_eq!(cursor, bits.len());
+    }
+
+    #[test]
+    fn repeated_values_cost_one_bit_each() {
+        let values: [i16; 10] = [1500; 10];
+        let mut bits = BitVec::<Msb0, u8>::new();
+        encode_values(&mut bits, &values);
+
+        // First sample in full (16 bits) + one `0` bit per repeat.
+        assert_eq!(bits.len(), 16 + (values.len() - 1));
+    }
+
+    #[test]
+    fn encode_decode_packs_both_streams() {
+        let timestamps: Vec<u64> = (0..16).map(|i| i * 1_000_000_000).collect();
+        let values: Vec<i16> = (0..16).map(|i| 1500 + (i % 3) as i16).collect();
+
+        let packed = encode(&timestamps, &values);
+        let (decoded_ts, decoded_values) = decode::<i16>(&packed).unwrap();
+
+        assert_eq!(decoded_ts, timestamps);
+        assert_eq!(decoded_values, values);
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use slice_deque::SliceDeque;
+
+/// A single logged event, timestamped to microsecond precision so interleaved I2C errors and
+/// sample events can be ordered precisely when reviewed remotely.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogRecord {
+    timestamp_us: u64,
+    message: String,
+}
+
+/// A fixed-size ring buffer of [`LogRecord`]s, mirroring how [`SampleBuf`][crate::samples::SampleBuf]
+/// bounds its own memory. Every record is also written to stderr as it's logged, so the buffer is
+/// purely for remote visibility (`GET /log`) rather than the only copy of the data.
+pub struct Logger {
+    buf: SliceDeque<LogRecord>,
+}
+
+impl Logger {
+    pub fn new(capacity: usize) -> Self {
+        Logger {
+            buf: SliceDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records `message`, writing it to stderr and appending it to the ring buffer (evicting the
+    /// oldest record if the buffer is full).
+    pub fn log(&mut self, message: impl Into<String>) {
+        let timestamp_us = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let record = LogRecord { timestamp_us, message: message.into() };
+
+        eprintln!("[{}] {}", record.timestamp_us, record.message);
+
+        if self.buf.is_full() {
+            self.buf.pop_front();
+        }
+        self.buf.push_back(record);
+    }
+
+    pub fn records(&self) -> &[LogRecord] {
+        &self.buf
+    }
+}
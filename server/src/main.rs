@@ -1,7 +1,10 @@
-use std::convert::Infallible;
+use std::convert::{Infallible, TryFrom};
+use std::str::FromStr;
 
 #[cfg(unix)]
 use std::path::Path;
+use std::io;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, SystemTime};
@@ -9,11 +12,29 @@ use tokio::sync::Mutex;
 use tokio::time::delay_for;
 
 use clap::{App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
+use fixed::types::{I8F24, I8F8};
+use futures::stream::{self, StreamExt};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
 use hyper::service::{make_service_fn, service_fn};
-use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use hyper::{body, Body, Method, Request, Response, Server, StatusCode};
+use serde::{Deserialize, Serialize};
 use serde_json;
+use tcn75a::Limits;
+use tokio::sync::broadcast;
 
+mod alert;
+mod coap;
+mod config;
+mod gorilla;
+mod log;
+mod replay;
 mod samples;
+mod tai64;
+use alert::{AlertMonitor, AlertStatus};
+use coap::CoapSamples;
+use config::Config;
+use log::Logger;
+use replay::ReplayFile;
 use samples::SampleBuf;
 
 #[cfg(unix)]
@@ -21,18 +42,150 @@ use i2cdev::core::*;
 #[cfg(unix)]
 use i2cdev::linux::{LinuxI2CDevice, LinuxI2CError};
 
+// Number of not-yet-delivered samples a `/stream` subscriber can fall behind by before it starts
+// missing samples (see `broadcast::error::RecvError::Lagged`).
+const STREAM_CHANNEL_CAPACITY: usize = 64;
+
+// How many log records to retain for `GET /log`; mirrors SampleBuf's fixed memory footprint.
+const LOG_CAPACITY: usize = 1024;
+
+// Default EWMA time constant, matching the tcn75a `plot` example's default.
+const DEFAULT_TAU_MS: u32 = 1000;
+
+// Standard CoAP port (RFC 7252 section 12.8), shared by both subcommands' CoAP resource alongside
+// the HTTP server on `cfg.bind`'s port.
+const DEFAULT_COAP_PORT: u16 = 5683;
+
+// Fixed-point exponential smoothing, mirroring the tcn75a `plot` example's recurrence
+// (`s_t = alpha*x_t + decay*s_{t-1}`, `alpha = 1 - decay`, carried in `I8F24`) but deriving
+// `decay` from the measure loop's actual sample interval and configured `tau` instead of a
+// baked-in 1 second.
+fn ewma_step(prev: Option<I8F24>, sample: I8F8, dt_ms: u32, tau_ms: u32) -> I8F24 {
+    // `decay`/`alpha` are carried in `I8F24` (not `I1F15`) because a small `tau_ms` relative to
+    // `dt_ms` makes `decay` round to exactly 0, so `alpha = 1 - decay` rounds to exactly 1 --
+    // outside I1F15's `[-1, 1)` range, which would panic in `from_num`.
+    let decay = I8F24::from_num((-(dt_ms as f64) / tau_ms as f64).exp());
+    let alpha = I8F24::from_num(1) - decay;
+
+    match prev {
+        Some(prev) => I8F24::from_num(alpha * I8F24::from_num(sample)) + prev * decay,
+        None => I8F24::from_num(sample),
+    }
+}
+
+/// A single posted sample, broadcast to `/stream` subscribers as it is produced.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct Sample {
+    timestamp: u64,
+    value: i16,
+}
+
+/// Body accepted by `POST /alert`: limits as decimal strings (e.g. `"25.5"`), parsed into
+/// [`I8F8`] before being validated through [`Limits::try_from`].
+#[derive(Deserialize)]
+struct AlertLimitsReq {
+    limit_low: String,
+    limit_high: String,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+fn bad_request(message: impl Into<String>) -> Response<Body> {
+    let body = ErrorBody { error: message.into() };
+    let mut resp = Response::new(Body::from(serde_json::to_string(&body).unwrap()));
+    *resp.status_mut() = StatusCode::BAD_REQUEST;
+    resp
+}
+
 async fn temp_service(
     req: Request<Body>,
     rx: Arc<Mutex<SampleBuf<i16>>>,
+    smoothed_rx: Arc<Mutex<SampleBuf<i16>>>,
+    stream_tx: broadcast::Sender<Sample>,
+    log: Arc<Mutex<Logger>>,
+    alert: Arc<Mutex<AlertMonitor>>,
 ) -> Result<Response<Body>, Infallible> {
     match (req.method(), req.uri().path()) {
+        // `?smoothed=1` serves the EWMA-smoothed series instead of raw samples (see `ewma_step`).
         (&Method::GET, "/") => {
-            let sample_buf = rx.lock().await;
+            let wants_smoothed = req.uri().query().map_or(false, |q| q.contains("smoothed=1"));
+            let buf = if wants_smoothed { &smoothed_rx } else { &rx };
+            let sample_buf = buf.lock().await;
             Ok(Response::new(Body::from(
                 serde_json::to_string(&*sample_buf).unwrap(),
             )))
         }
 
+        (&Method::GET, "/log") => {
+            let logger = log.lock().await;
+            Ok(Response::new(Body::from(
+                serde_json::to_string(logger.records()).unwrap(),
+            )))
+        }
+
+        (&Method::GET, "/alert") => {
+            let monitor = alert.lock().await;
+            Ok(Response::new(Body::from(
+                serde_json::to_string(&AlertStatus::from(&*monitor)).unwrap(),
+            )))
+        }
+
+        (&Method::POST, "/alert") => {
+            let body_bytes = match body::to_bytes(req.into_body()).await {
+                Ok(bytes) => bytes,
+                Err(e) => return Ok(bad_request(format!("failed to read request body: {}", e))),
+            };
+
+            let req: AlertLimitsReq = match serde_json::from_slice(&body_bytes) {
+                Ok(req) => req,
+                Err(e) => return Ok(bad_request(format!("invalid JSON body: {}", e))),
+            };
+
+            let (lo, hi) = match (I8F8::from_str(&req.limit_low), I8F8::from_str(&req.limit_high)) {
+                (Ok(lo), Ok(hi)) => (lo, hi),
+                _ => return Ok(bad_request("limit_low/limit_high must be decimal numbers")),
+            };
+
+            match Limits::try_from((lo, hi)) {
+                Ok(limits) => {
+                    let mut monitor = alert.lock().await;
+                    monitor.set_limits(limits);
+                    Ok(Response::new(Body::from(
+                        serde_json::to_string(&AlertStatus::from(&*monitor)).unwrap(),
+                    )))
+                }
+                Err(e) => Ok(bad_request(e.to_string())),
+            }
+        }
+
+        // Server-Sent-Events framing: one `data: {...}\n\n` line per new sample, pushed as soon
+        // as `measure`/`replay_synthesize` post it, instead of making clients poll `/`.
+        (&Method::GET, "/stream") => {
+            let sub = stream_tx.subscribe();
+            let body_stream = stream::unfold(sub, |mut sub| async move {
+                loop {
+                    match sub.recv().await {
+                        Ok(sample) => {
+                            let line = format!("data: {}\n\n", serde_json::to_string(&sample).unwrap());
+                            return Some((Ok::<_, Infallible>(line), sub));
+                        }
+                        // A slow subscriber missed some samples; keep streaming from where the
+                        // channel picks back up instead of tearing down the connection.
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            });
+
+            let mut resp = Response::new(Body::wrap_stream(body_stream));
+            resp.headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+            Ok(resp)
+        }
+
         _ => {
             let mut not_found = Response::default();
             *not_found.status_mut() = StatusCode::NOT_FOUND;
@@ -41,30 +194,100 @@ async fn temp_service(
     }
 }
 
-// real code should probably not use unwrap()
+// The server talks to the sensor directly over i2cdev rather than through the `tcn75a` crate, so
+// its raw readings are scaled in 1/16ths of a degree (see `src/main.rs`'s `i2cfun`) rather than
+// the `tcn75a::Temperature` Q8.8 format. Converting to `I8F8` lets both share `Limits`/`LimitError`.
+pub(crate) fn raw_to_i8f8(raw: i16) -> I8F8 {
+    I8F8::from_num(raw) / 16
+}
+
 #[cfg(unix)]
-async fn measure<P>(path: P, addr: u16, tx: Arc<Mutex<SampleBuf<i16>>>) -> Result<(), ()>
+async fn measure<P>(
+    path: P,
+    addr: u16,
+    tx: Arc<Mutex<SampleBuf<i16>>>,
+    smoothed_tx: Arc<Mutex<SampleBuf<i16>>>,
+    tau_ms: u32,
+    stream_tx: broadcast::Sender<Sample>,
+    log: Arc<Mutex<Logger>>,
+    alert: Arc<Mutex<AlertMonitor>>,
+) -> Result<(), ()>
 where
     P: AsRef<Path>,
 {
-    let mut dev = LinuxI2CDevice::new(path, addr).unwrap();
+    let mut prev_ewma: Option<I8F24> = None;
+    let mut prev_sample_time: Option<SystemTime> = None;
+    let mut dev = match LinuxI2CDevice::new(path, addr) {
+        Ok(dev) => dev,
+        Err(e) => {
+            log.lock().await.log(format!("failed to open I2C device: {}", e));
+            return Err(());
+        }
+    };
 
-    dev.smbus_write_byte_data(0x01, 0x60).unwrap();
+    if let Err(e) = dev.smbus_write_byte_data(0x01, 0x60) {
+        log.lock().await.log(format!("failed to write sensor config register: {}", e));
+        return Err(());
+    }
 
     loop {
         let now = SystemTime::now();
 
         // Measured: takes approx 1 millisecond.
-        let raw = i16::from_be(dev.smbus_read_word_data(0x00).unwrap() as i16) >> 4;
+        let raw = match dev.smbus_read_word_data(0x00) {
+            Ok(word) => i16::from_be(word as i16) >> 4,
+            Err(e) => {
+                log.lock().await.log(format!("I2C read error: {}", e));
+                return Err(());
+            }
+        };
 
         let mut lock = tx.lock().await;
         lock.post(now, raw).map_err(|_| ())?;
+        drop(lock);
+
+        // Derive dt from the actual gap between samples rather than assuming the nominal 1 s loop
+        // period always holds exactly; the first sample has no prior reading to measure from, so
+        // it falls back to that nominal period.
+        let dt_ms = match prev_sample_time {
+            Some(prev) => now
+                .duration_since(prev)
+                .map(|d| d.as_millis().max(1) as u32)
+                .unwrap_or(1000),
+            None => 1000,
+        };
+        prev_sample_time = Some(now);
+
+        let smoothed = ewma_step(prev_ewma, raw_to_i8f8(raw), dt_ms, tau_ms);
+        prev_ewma = Some(smoothed);
+        let smoothed_raw = (smoothed.to_num::<f32>() * 16.0) as i16;
+        let mut smoothed_lock = smoothed_tx.lock().await;
+        smoothed_lock.post(now, smoothed_raw).map_err(|_| ())?;
+        drop(smoothed_lock);
+
+        let timestamp = now.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| ())?.as_secs();
+        log.lock().await.log(format!("sample posted: {}", raw));
+        // No subscribers is not an error; just means nobody's watching `/stream` right now.
+        let _ = stream_tx.send(Sample { timestamp, value: raw });
+
+        let was_asserted = alert.lock().await.asserted();
+        let is_asserted = alert.lock().await.update(raw_to_i8f8(raw));
+        if is_asserted != was_asserted {
+            log.lock().await.log(format!(
+                "alert {}",
+                if is_asserted { "asserted" } else { "cleared" }
+            ));
+        }
 
         delay_for(Duration::from_millis(1000)).await;
     }
 }
 
-async fn replay_synthesize(tx: Arc<Mutex<SampleBuf<i16>>>) -> Result<(), ()> {
+async fn replay_synthesize(
+    tx: Arc<Mutex<SampleBuf<i16>>>,
+    stream_tx: broadcast::Sender<Sample>,
+    log: Arc<Mutex<Logger>>,
+) -> Result<(), ()> {
     let mut fake_temp: i16 = -1024;
 
     loop {
@@ -72,8 +295,18 @@ async fn replay_synthesize(tx: Arc<Mutex<SampleBuf<i16>>>) -> Result<(), ()> {
         thread::sleep(Duration::from_millis(1));
         let mut lock = tx.lock().await;
         lock.post(now, fake_temp)?;
+        let len = lock.len();
+        let capacity = lock.capacity();
+        drop(lock);
+
+        let timestamp = now.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| ())?.as_secs();
+        log.lock().await.log(format!("synthesized sample posted: {}", fake_temp));
+        let _ = stream_tx.send(Sample {
+            timestamp,
+            value: fake_temp,
+        });
 
-        if lock.len() == lock.capacity() {
+        if len == capacity {
             break;
         }
 
@@ -84,6 +317,34 @@ async fn replay_synthesize(tx: Arc<Mutex<SampleBuf<i16>>>) -> Result<(), ()> {
     Ok(())
 }
 
+// Replays a previously captured `ReplayFile`, posting samples in their original order at the
+// interval implied by the capture's `sample_rate` (per-sample timestamps aren't preserved on
+// disk; `SampleBuf` itself only ever assumed a constant interval between posts).
+async fn replay_captured(
+    tx: Arc<Mutex<SampleBuf<i16>>>,
+    stream_tx: broadcast::Sender<Sample>,
+    log: Arc<Mutex<Logger>>,
+    captured: ReplayFile,
+) -> Result<(), ()> {
+    let interval_ms = 1000 / captured.sample_rate().max(1) as u64;
+
+    for &sample in captured.into_sample_buf().iter() {
+        let now = SystemTime::now();
+        let mut lock = tx.lock().await;
+        lock.post(now, sample)?;
+        drop(lock);
+
+        let timestamp = now.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| ())?.as_secs();
+        log.lock().await.log(format!("replayed sample posted: {}", sample));
+        let _ = stream_tx.send(Sample { timestamp, value: sample });
+
+        delay_for(Duration::from_millis(interval_ms)).await;
+    }
+
+    log.lock().await.log("replay file exhausted");
+    Ok(())
+}
+
 fn parse_args<'a>() -> ArgMatches<'a> {
     App::new("I2C Sensor Server")
         .version("0.1")
@@ -97,18 +358,13 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 .value_name("RATE")
                 .takes_value(true),
         )
-        .arg(
-            Arg::with_name("IP_ADDRESS")
-                .help("IP Address and Port")
-                .default_value("0.0.0.0:8000")
-                .index(1),
-        )
+        .arg(Arg::with_name("IP_ADDRESS").help("IP Address and Port").index(1))
         .subcommand(
             SubCommand::with_name("measure")
                 .about("Run the server and obtain data from I2C sensors (Unix only).")
                 .arg(
                     Arg::with_name("replay")
-                        .help("Write data to file for replay on exit (not implemented).")
+                        .help("Write captured samples to FILE on shutdown (Ctrl-C), for replay.")
                         .short("r")
                         .value_name("FILE")
                         .takes_value(true),
@@ -121,15 +377,23 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                         .takes_value(true),
                 )
                 .arg(
-                    Arg::with_name("NODE")
-                        .help("I2C device node")
-                        .required(true)
-                        .index(1),
+                    Arg::with_name("config")
+                        .help("Key=value config file; CLI args override its contents.")
+                        .long("config")
+                        .value_name("FILE")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("tau")
+                        .help("EWMA smoothing time constant in ms (default 1000).")
+                        .long("tau")
+                        .value_name("MILLISECONDS")
+                        .takes_value(true),
                 )
+                .arg(Arg::with_name("NODE").help("I2C device node").index(1))
                 .arg(
                     Arg::with_name("I2C_ADDRESS")
                         .help("I2C device address")
-                        .required(true)
                         .index(2),
                 ),
         )
@@ -143,9 +407,23 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 )
                 .arg(
                     Arg::with_name("file")
-                        .help("Replay data file to read (not implemented).")
+                        .help("Replay capture file to read, written by `measure -r FILE`.")
                         .index(1),
                 )
+                .arg(
+                    Arg::with_name("config")
+                        .help("Key=value config file; CLI args override its contents.")
+                        .long("config")
+                        .value_name("FILE")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("tau")
+                        .help("EWMA smoothing time constant in ms (default 1000).")
+                        .long("tau")
+                        .value_name("MILLISECONDS")
+                        .takes_value(true),
+                )
                 .group(
                     ArgGroup::with_name("source")
                         .args(&["file", "synthesis"])
@@ -155,6 +433,72 @@ fn parse_args<'a>() -> ArgMatches<'a> {
         .get_matches()
 }
 
+// Builds the effective config for a subcommand by reading its `--config FILE` (if any) and
+// overlaying whatever was also passed on the command line (CLI wins). Fails if `--config` names a
+// file `Config::from_file` can't read, rather than panicking on ordinary user error.
+fn effective_config(matches: &ArgMatches, sub_matches: &ArgMatches) -> io::Result<Config> {
+    let file_cfg = match sub_matches.value_of("config") {
+        Some(path) => Config::from_file(path)?,
+        None => Config::default(),
+    };
+
+    let cli_cfg = Config {
+        bind: matches.value_of("IP_ADDRESS").map(String::from),
+        sample_rate: matches.value_of("sample_rate").map(|s| s.parse().unwrap()),
+        node: sub_matches.value_of("NODE").map(String::from),
+        addr: sub_matches
+            .value_of("I2C_ADDRESS")
+            .map(|s| u16::from_str_radix(s, 16).unwrap()),
+        device: sub_matches.value_of("device").map(String::from),
+        limit_low: None,
+        limit_high: None,
+        tau_ms: sub_matches.value_of("tau").map(|s| s.parse().unwrap()),
+    };
+
+    Ok(file_cfg.merge(cli_cfg))
+}
+
+// `effective_config`'s error path for both subcommands: `--config FILE` naming a file that can't
+// be read is a user-facing CLI error, so it's reported and bails out the same way clap's own
+// `get_matches` does for a malformed argument, rather than panicking.
+fn effective_config_or_exit(matches: &ArgMatches, sub_matches: &ArgMatches) -> Config {
+    match effective_config(matches, sub_matches) {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("error: failed to read config file: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Parses `limit_low`/`limit_high` out of a merged `Config`, if both were supplied, into a
+// validated `Limits`. Returns `None` (alert monitoring disabled) if either is absent.
+fn configured_limits(cfg: &Config) -> Option<Limits> {
+    let lo = I8F8::from_str(cfg.limit_low.as_deref()?).ok()?;
+    let hi = I8F8::from_str(cfg.limit_high.as_deref()?).ok()?;
+    Limits::try_from((lo, hi)).ok()
+}
+
+// `coap-handler`'s `Handler` contract is blocking, so the CoAP resource is served off a dedicated
+// OS thread (its own little reactor, one UDP socket) rather than a tokio task, mirroring how
+// `measure`/`replay_*` get their own async task instead of sharing `temp_service`'s.
+fn spawn_coap_server(bind: &str, samples: Arc<Mutex<SampleBuf<i16>>>, log: Arc<Mutex<Logger>>) {
+    let bind = bind.to_string();
+
+    thread::spawn(move || {
+        let mut handler = CoapSamples::new(samples);
+
+        match coap_server::UdpTransport::bind(&bind) {
+            Ok(mut transport) => loop {
+                if let Err(e) = transport.serve_one(&mut handler) {
+                    log.blocking_lock().log(format!("CoAP request failed: {}", e));
+                }
+            },
+            Err(e) => log.blocking_lock().log(format!("failed to bind CoAP listener on {}: {}", bind, e)),
+        }
+    });
+}
+
 #[tokio::main]
 async fn main() {
     let matches = parse_args();
@@ -162,36 +506,144 @@ async fn main() {
     let i2c_tx = Arc::new(Mutex::new(SampleBuf::<i16>::new(86400, 1)));
     let i2c_rx = Arc::clone(&i2c_tx);
 
-    let make_svc = make_service_fn(|_conn| {
-        let foo = Arc::clone(&i2c_rx);
+    let smoothed_tx = Arc::new(Mutex::new(SampleBuf::<i16>::new(86400, 1)));
+    let smoothed_rx = Arc::clone(&smoothed_tx);
 
-        async {
-            Ok::<_, Infallible>(service_fn(move |body: Request<Body>| {
-                temp_service(body, Arc::clone(&foo))
-            }))
-        }
-    });
+    let (stream_tx, _) = broadcast::channel::<Sample>(STREAM_CHANNEL_CAPACITY);
+    let log = Arc::new(Mutex::new(Logger::new(LOG_CAPACITY)));
+
+    if let Some(sub_matches) = matches.subcommand_matches("measure") {
+        let cfg = effective_config_or_exit(&matches, sub_matches);
+        let addr: SocketAddr = cfg.bind.as_deref().unwrap_or("0.0.0.0:8000").parse().unwrap();
+        let alert = Arc::new(Mutex::new(AlertMonitor::new(configured_limits(&cfg))));
 
-    let addr = matches.value_of("IP_ADDRESS").unwrap().parse().unwrap();
-    let server = Server::bind(&addr).serve(make_svc);
+        let tau_ms = cfg.tau_ms.unwrap_or(DEFAULT_TAU_MS);
+
+        let coap_addr = SocketAddr::new(addr.ip(), DEFAULT_COAP_PORT);
+        spawn_coap_server(&coap_addr.to_string(), Arc::clone(&i2c_rx), Arc::clone(&log));
+
+        let make_svc = make_service_fn(|_conn| {
+            let foo = Arc::clone(&i2c_rx);
+            let smoothed_foo = Arc::clone(&smoothed_rx);
+            let stream_tx = stream_tx.clone();
+            let log = Arc::clone(&log);
+            let alert = Arc::clone(&alert);
+
+            async {
+                Ok::<_, Infallible>(service_fn(move |body: Request<Body>| {
+                    temp_service(
+                        body,
+                        Arc::clone(&foo),
+                        Arc::clone(&smoothed_foo),
+                        stream_tx.clone(),
+                        Arc::clone(&log),
+                        Arc::clone(&alert),
+                    )
+                }))
+            }
+        });
+        // Disable Nagle's algorithm: a single 1 Hz sample is small enough that coalescing would
+        // otherwise add up to ~200ms of needless latency to near-real-time charting.
+        let server = Server::bind(&addr).tcp_nodelay(true).serve(make_svc);
 
-    if let Some(matches) = matches.subcommand_matches("measure") {
         #[cfg(unix)]
         {
-            let i2c_node = matches.value_of("NODE").unwrap();
-            let i2c_addr =
-                u16::from_str_radix(matches.value_of("I2C_ADDRESS").unwrap(), 16).unwrap();
-            let (_, _) = tokio::join!(measure(i2c_node, i2c_addr, i2c_tx), server);
+            let i2c_node = cfg.node.expect("I2C device node required (NODE arg or `node=` in --config)");
+            let i2c_addr = cfg
+                .addr
+                .expect("I2C device address required (I2C_ADDRESS arg or `addr=` in --config)");
+            let replay_path = sub_matches.value_of("replay").map(String::from);
+            let i2c_tx_snapshot = Arc::clone(&i2c_tx);
+            let log_ctrlc = Arc::clone(&log);
+
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    if let Some(path) = replay_path {
+                        let buf = i2c_tx_snapshot.lock().await;
+                        match ReplayFile::capture(&buf).save(&path) {
+                            Ok(()) => log_ctrlc.lock().await.log(format!("replay capture written to {}", path)),
+                            Err(e) => log_ctrlc.lock().await.log(format!("failed to write replay capture: {}", e)),
+                        }
+                    } else {
+                        log_ctrlc.lock().await.log("shutting down (no --replay FILE given, nothing captured)");
+                    }
+                }
+                _ = async {
+                    tokio::join!(
+                        measure(
+                            i2c_node,
+                            i2c_addr,
+                            i2c_tx,
+                            smoothed_tx,
+                            tau_ms,
+                            stream_tx.clone(),
+                            Arc::clone(&log),
+                            alert
+                        ),
+                        server
+                    )
+                } => {}
+            }
         }
 
         #[cfg(windows)]
-        println!("Measure subcommand only available on Unix systems.");
-    } else if let Some(matches) = matches.subcommand_matches("replay") {
-        if matches.is_present("synthesis") {
-            let replay_fn = replay_synthesize(i2c_tx);
+        log.lock().await.log("measure subcommand only available on Unix systems");
+    } else if let Some(sub_matches) = matches.subcommand_matches("replay") {
+        let cfg = effective_config_or_exit(&matches, sub_matches);
+        let addr: SocketAddr = cfg.bind.as_deref().unwrap_or("0.0.0.0:8000").parse().unwrap();
+        let alert = Arc::new(Mutex::new(AlertMonitor::new(configured_limits(&cfg))));
+
+        let coap_addr = SocketAddr::new(addr.ip(), DEFAULT_COAP_PORT);
+        spawn_coap_server(&coap_addr.to_string(), Arc::clone(&i2c_rx), Arc::clone(&log));
+
+        let make_svc = make_service_fn(|_conn| {
+            let foo = Arc::clone(&i2c_rx);
+            let smoothed_foo = Arc::clone(&smoothed_rx);
+            let stream_tx = stream_tx.clone();
+            let log = Arc::clone(&log);
+            let alert = Arc::clone(&alert);
+
+            async {
+                Ok::<_, Infallible>(service_fn(move |body: Request<Body>| {
+                    temp_service(
+                        body,
+                        Arc::clone(&foo),
+                        Arc::clone(&smoothed_foo),
+                        stream_tx.clone(),
+                        Arc::clone(&log),
+                        Arc::clone(&alert),
+                    )
+                }))
+            }
+        });
+        let server = Server::bind(&addr).tcp_nodelay(true).serve(make_svc);
+
+        if sub_matches.is_present("synthesis") {
+            let replay_fn = replay_synthesize(i2c_tx, stream_tx.clone(), Arc::clone(&log));
             let (_, _) = tokio::join!(replay_fn, server);
         } else {
-            println!("Replay from file not yet implemented.");
+            let file = sub_matches.value_of("file").expect("file or synthesis required (see `source` ArgGroup)");
+            match ReplayFile::load(file) {
+                Ok(captured) => {
+                    let replay_fn = replay_captured(i2c_tx, stream_tx.clone(), Arc::clone(&log), captured);
+                    let (_, _) = tokio::join!(replay_fn, server);
+                }
+                Err(e) => log.lock().await.log(format!("failed to load replay file {}: {}", file, e)),
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ewma_step_small_tau_does_not_panic() {
+        // `tau_ms` well below `dt_ms` drives `decay` to (round to) 0, so `alpha` rounds to
+        // exactly 1 -- regression test for the I1F15 overflow panic this used to hit.
+        let sample = I8F8::from_num(25);
+        let smoothed = ewma_step(Some(I8F24::from_num(20)), sample, 1000, 50);
+        assert_eq!(smoothed, I8F24::from_num(sample));
+    }
+}
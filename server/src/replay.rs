@@ -0,0 +1,52 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::samples::SampleBuf;
+use crate::tai64::Tai64N;
+
+/// On-disk layout for a captured `measure` session, loadable back into `replay`.
+///
+/// Every capture records `version`. [`ReplayFile::load`] does not check it: a capture whose fields
+/// don't match the current layout fails to load with `InvalidData`.
+const FORMAT_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize)]
+pub struct ReplayFile {
+    version: u32,
+    sample_rate: u8,
+    timestamp: Option<Tai64N>,
+    samples: Vec<i16>,
+}
+
+impl ReplayFile {
+    /// Snapshots a live `SampleBuf` for writing to disk.
+    pub fn capture(buf: &SampleBuf<i16>) -> Self {
+        ReplayFile {
+            version: FORMAT_VERSION,
+            sample_rate: buf.sample_rate(),
+            timestamp: buf.timestamp(),
+            samples: buf.to_vec(),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn sample_rate(&self) -> u8 {
+        self.sample_rate
+    }
+
+    pub fn into_sample_buf(self) -> SampleBuf<i16> {
+        SampleBuf::from_parts(self.timestamp, self.sample_rate, &self.samples)
+    }
+}
@@ -1,65 +1,127 @@
+use std::mem::size_of;
 use std::ops::Deref;
-use std::time::{SystemTime, Duration};
+use std::time::SystemTime;
 
 use base64::{encode_config_slice, URL_SAFE};
 use serde::{Serialize, Deserialize, Serializer, Deserializer, ser::SerializeStruct};
 use slice_deque::SliceDeque;
 
+use crate::gorilla;
+use crate::tai64::Tai64N;
+
+/// How [`SampleBuf`]'s `Serialize` impl packs the buffer's samples into the `buf` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SerializeMode {
+    /// The buffer's raw bytes, base64-url encoded. Simple, but doesn't exploit a
+    /// slowly-changing, regularly-sampled series at all.
+    Raw,
+    /// Gorilla-style delta-of-delta timestamps and XOR-coded values (see the [`gorilla`] module),
+    /// bit-packed and then base64-url encoded like [`Raw`](SerializeMode::Raw). Dramatically
+    /// smaller for a series like temperature, which rarely changes between samples.
+    Compressed,
+}
+
 pub struct SampleBuf<T> {
-    timestamp: u64,
+    timestamp: Option<Tai64N>,
     sample_rate: u8,
+    mode: SerializeMode,
+    generation: u64,
     buf: SliceDeque<T>
 }
 
 impl<T> SampleBuf<T> {
     pub fn new(capacity : usize, sample_rate: u8) -> Self {
         SampleBuf {
-            timestamp: 0,
+            timestamp: None,
             sample_rate: sample_rate,
+            mode: SerializeMode::Raw,
+            generation: 0,
             buf: SliceDeque::with_capacity(capacity)
         }
     }
 
+    /// Bumped by one on every successful [`post`](SampleBuf::post), regardless of how many
+    /// sampling intervals it padded in. Lets an observer (e.g. a CoAP resource) cheaply tell
+    /// whether the buffer has changed since it last looked, without comparing contents.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Selects how `Serialize` packs this buffer's samples; see [`SerializeMode`].
+    pub fn set_mode(&mut self, mode: SerializeMode) {
+        self.mode = mode;
+    }
+
+    pub fn mode(&self) -> SerializeMode {
+        self.mode
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn timestamp(&self) -> Option<Tai64N> {
+        self.timestamp
+    }
+
+    pub fn sample_rate(&self) -> u8 {
+        self.sample_rate
+    }
+}
+
+impl<T: Copy> SampleBuf<T> {
+    /** Posts a new measurement taken at `now`, padding with repeats of `sample` for any
+    sampling interval that elapsed without a post (e.g. a scheduling hiccup skipped a tick).
+
+    The gap between `now` and the previous post is measured between their TAI64N labels. Those
+    labels follow the system clock's UNIX time with no leap-second correction (see [`Tai64N`]),
+    so a second the clock repeats for a leap second is repeated in the labels too. */
     pub fn post(&mut self, now: SystemTime, sample: T) -> Result<(), ()> {
-        let prev_systime = SystemTime::UNIX_EPOCH + Duration::from_secs(self.timestamp);
-
-        let leap_sec = match now.duration_since(prev_systime) {
-            Ok(dur) => {
-                // Posting a new measurement does not support zero duration between
-                // measurements.
-                self.timestamp = now.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| ())?.as_secs();
-                dur == Duration::new(0, 0)
+        let label = Tai64N::from_system_time(now).map_err(|_| ())?;
+
+        let intervals = match (self.timestamp, self.sample_rate) {
+            (Some(prev), rate) if rate > 0 => match label.duration_since(prev) {
+                Some(gap) => {
+                    let interval_nanos = 1_000_000_000u128 / u128::from(rate);
+                    let gap_nanos = gap.as_nanos();
+                    ((gap_nanos + interval_nanos / 2) / interval_nanos).max(1) as usize
+                }
+                // The system clock stepped backwards; treat it like an ordinary single-tick
+                // post rather than guessing how many intervals "really" elapsed.
+                None => 1,
             },
-            Err(_e) => {
-                // TODO: We can handle up to 1 second duration in the past of samples.
-                // We should fail if the system clock was updated, however.
-                // Also handle case where elapsed time is >= 2 sampling times since
-                // previous sample.
-                true
-            }
+            _ => 1,
         };
+        self.timestamp = Some(label);
 
-        if self.buf.is_full() {
-            self.buf.pop_front();
-        }
-
-        if !leap_sec {
-            self.buf.push_back(sample);
-        } else {
-            // TODO: Leap "seconds" will be encoded specially when compression
-            // is implemented.
+        for _ in 0..intervals.min(self.buf.capacity().max(1)) {
+            if self.buf.is_full() {
+                self.buf.pop_front();
+            }
             self.buf.push_back(sample);
         }
+        self.generation = self.generation.wrapping_add(1);
 
         Ok(())
     }
 
-    pub fn capacity(&self) -> usize {
-        self.buf.capacity()
-    }
+    /// Rebuilds a `SampleBuf` from its constituent parts, e.g. when loading a replay capture
+    /// back off disk. `samples` is taken oldest-first, same order as iterating `&*buf`.
+    pub fn from_parts(timestamp: Option<Tai64N>, sample_rate: u8, samples: &[T]) -> Self {
+        let mut buf = SliceDeque::with_capacity(samples.len());
+        buf.extend(samples.iter().copied());
 
-    pub fn len(&self) -> usize {
-        self.buf.len()
+        SampleBuf {
+            timestamp,
+            sample_rate,
+            mode: SerializeMode::Raw,
+            generation: 0,
+            buf,
+        }
     }
 }
 
@@ -71,25 +133,33 @@ impl<T> Deref for SampleBuf<T> {
     }
 }
 
-impl<T> Serialize for SampleBuf<T> where T: private::Sealed {
+impl<T> Serialize for SampleBuf<T> where T: private::Sealed + Copy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SampleBuf", 3)?;
+        let mut state = serializer.serialize_struct("SampleBuf", 4)?;
         state.serialize_field("timestamp", &self.timestamp)?;
         state.serialize_field("sample_rate", &self.sample_rate)?;
+        state.serialize_field("mode", &self.mode)?;
+
+        let bytes = match self.mode {
+            SerializeMode::Raw => {
+                let temp_ptr = &**self as *const [T] as *const T as *const u8;
+                unsafe { std::slice::from_raw_parts(temp_ptr, self.len() * size_of::<T>()) }.to_vec()
+            }
+            SerializeMode::Compressed => {
+                let interval_nanos = 1_000_000_000u64 / u64::from(self.sample_rate.max(1));
+                let timestamps: Vec<u64> = (0..self.len() as u64).map(|i| i * interval_nanos).collect();
+                gorilla::encode(&timestamps, &self[..])
+            }
+        };
 
-        let max_base64_size = self.capacity() * 4 / 3 + 4;
+        let max_base64_size = bytes.len() * 4 / 3 + 4;
         let mut payload = Vec::<u8>::with_capacity(max_base64_size);
         payload.resize(max_base64_size, 0);
 
-        let byte_data = {
-            let temp_ptr = &**self as *const [T] as *const T as *const u8;
-            unsafe { std::slice::from_raw_parts(temp_ptr, self.len() * 2) }
-        };
-
-        let written = encode_config_slice(byte_data, URL_SAFE, &mut payload);
+        let written = encode_config_slice(&bytes, URL_SAFE, &mut payload);
         payload.resize(written, 0);
 
         // Base64 data will already be ASCII, which is UTF-8 subset.
@@ -0,0 +1,146 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Offset between the TAI64 and UNIX epochs: TAI64 labels count SI seconds from `-(1<<62)`, and
+/// 1970-01-01 UTC was 10 TAI seconds ahead of UTC at the time, so the UNIX epoch is `10 + 2^62`
+/// seconds into the TAI64 label space.
+const TAI64_EPOCH_OFFSET: u64 = 10 + (1u64 << 62);
+
+/// A TAI64N label: a count of seconds since the TAI64 epoch, plus nanoseconds within that
+/// second. [`Tai64N::from_system_time`] derives it from the system clock's UNIX time plus the
+/// fixed [`TAI64_EPOCH_OFFSET`]; no leap-second table is applied, so the gap between two labels
+/// is exactly the gap between the two `SystemTime`s, including across a leap second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Tai64N {
+    seconds: u64,
+    nanos: u32,
+}
+
+impl Tai64N {
+    /// The TAI64N label for the UNIX epoch (1970-01-01 00:00:00 UTC).
+    pub const UNIX_EPOCH: Tai64N = Tai64N {
+        seconds: TAI64_EPOCH_OFFSET,
+        nanos: 0,
+    };
+
+    /// Converts a `SystemTime` to its TAI64N label. Fails only if `time` predates the UNIX epoch,
+    /// which `SystemTime::now()` never does in practice.
+    pub fn from_system_time(time: SystemTime) -> Result<Tai64N, ()> {
+        let since_epoch = time.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| ())?;
+
+        Ok(Tai64N {
+            seconds: TAI64_EPOCH_OFFSET + since_epoch.as_secs(),
+            nanos: since_epoch.subsec_nanos(),
+        })
+    }
+
+    /// The canonical 12-byte wire representation: the 8-byte big-endian second count, followed
+    /// by the 4-byte big-endian nanosecond count.
+    pub fn to_bytes(self) -> [u8; 12] {
+        let mut bytes = [0u8; 12];
+        bytes[..8].copy_from_slice(&self.seconds.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.nanos.to_be_bytes());
+        bytes
+    }
+
+    /// The inverse of [`to_bytes`](Tai64N::to_bytes).
+    pub fn from_bytes(bytes: [u8; 12]) -> Tai64N {
+        Tai64N {
+            seconds: u64::from_be_bytes(bytes[..8].try_into().unwrap()),
+            nanos: u32::from_be_bytes(bytes[8..].try_into().unwrap()),
+        }
+    }
+
+    /// The time elapsed from `earlier` to `self`, or `None` if `self` is actually earlier (e.g.
+    /// the system clock was stepped backwards).
+    pub fn duration_since(self, earlier: Tai64N) -> Option<Duration> {
+        if self < earlier {
+            return None;
+        }
+
+        let (seconds, nanos) = if self.nanos >= earlier.nanos {
+            (self.seconds - earlier.seconds, self.nanos - earlier.nanos)
+        } else {
+            (
+                self.seconds - earlier.seconds - 1,
+                1_000_000_000 + self.nanos - earlier.nanos,
+            )
+        };
+
+        Some(Duration::new(seconds, nanos))
+    }
+}
+
+impl Serialize for Tai64N {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tai64N {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Tai64N, D::Error> {
+        struct Tai64NVisitor;
+
+        impl<'de> Visitor<'de> for Tai64NVisitor {
+            type Value = Tai64N;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("12 bytes encoding a TAI64N label")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Tai64N, E> {
+                let bytes: [u8; 12] = v
+                    .try_into()
+                    .map_err(|_| E::invalid_length(v.len(), &self))?;
+                Ok(Tai64N::from_bytes(bytes))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Tai64N, A::Error> {
+                let mut bytes = [0u8; 12];
+                for (i, slot) in bytes.iter_mut().enumerate() {
+                    *slot = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(Tai64N::from_bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(Tai64NVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_epoch_round_trips() {
+        let label = Tai64N::from_system_time(SystemTime::UNIX_EPOCH).unwrap();
+        assert_eq!(label, Tai64N::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let label = Tai64N::from_system_time(SystemTime::UNIX_EPOCH + Duration::new(1, 500)).unwrap();
+        assert_eq!(Tai64N::from_bytes(label.to_bytes()), label);
+    }
+
+    #[test]
+    fn duration_since_is_exact() {
+        let early = Tai64N::from_system_time(SystemTime::UNIX_EPOCH + Duration::new(10, 0)).unwrap();
+        let late = Tai64N::from_system_time(SystemTime::UNIX_EPOCH + Duration::new(12, 500)).unwrap();
+        assert_eq!(late.duration_since(early), Some(Duration::new(2, 500)));
+    }
+
+    #[test]
+    fn duration_since_rejects_going_backwards() {
+        let early = Tai64N::from_system_time(SystemTime::UNIX_EPOCH).unwrap();
+        let late = Tai64N::from_system_time(SystemTime::UNIX_EPOCH + Duration::new(1, 0)).unwrap();
+        assert_eq!(early.duration_since(late), None);
+    }
+}
@@ -1,5 +1,5 @@
 use cfg_if::cfg_if;
-use fixed::types::{I8F8, I1F15, I8F24};
+use fixed::types::{I8F8, I8F24};
 use fixed_macro::fixed;
 
 cfg_if! {
@@ -30,7 +30,9 @@ cfg_if! {
             #[argh(option, short='r', default = "default_resolution()", from_str_fn(get_resolution), description = "sample resolution")]
             res: Resolution,
             #[argh(option, short='o', description = "out json file")]
-            out_file: Option<String>
+            out_file: Option<String>,
+            #[argh(option, short='t', default = "default_tau()", description = "EWMA time constant (ms)")]
+            tau: u32
         }
 
         #[derive(Debug)]
@@ -57,6 +59,10 @@ cfg_if! {
             100
         }
 
+        fn default_tau() -> u32 {
+            1000
+        }
+
         fn default_resolution() -> Resolution {
             Resolution::Bits11
         }
@@ -117,18 +123,12 @@ fn main() -> Result<(), PlotError> {
 
     let mut prev_ewma: Option<I8F24> = None;
 
-    const EXP_DECAY_30: I1F15 = fixed_macro::fixed!(0.970445: I1F15); // e^(-30.0ms / 1000.0ms)
-    const EXP_DECAY_60: I1F15 = fixed_macro::fixed!(0.941764: I1F15); // e^(-60.0ms / 1000.0ms)
-    const EXP_DECAY_120: I1F15 = fixed_macro::fixed!(0.886920: I1F15); // e^(-120.0ms / 1000.0ms)
-    const EXP_DECAY_240: I1F15 = fixed_macro::fixed!(0.786627: I1F15); // e^(-240.0ms / 1000.0ms)
-
-    let decay = match sample_time {
-        30 => EXP_DECAY_30,
-        60 => EXP_DECAY_60,
-        120 => EXP_DECAY_120,
-        240 => EXP_DECAY_240,
-        _ => unreachable!()
-    };
+    // decay = e^(-dt / tau), computed for whatever sample interval the chosen resolution (and
+    // tau) actually produce, rather than only the four hardcoded intervals this used to support.
+    // Carried in `I8F24` (not `I1F15`): a small `tau` relative to `dt` makes `decay` round to
+    // exactly 0, so `alpha = 1 - decay` rounds to exactly 1- outside I1F15's `[-1, 1)` range,
+    // which would panic in `from_num`.
+    let decay = I8F24::from_num((-(sample_time as f64) / args.tau as f64).exp());
 
     (0..args.num)
         .zip(iter::repeat_with(|| tcn.temperature()))
@@ -140,7 +140,7 @@ fn main() -> Result<(), PlotError> {
             let temp = t?;
             let smooth_temp: I8F24;
 
-            let alpha = I1F15::from_num(fixed!(1.0: I8F24) - I8F24::from_num(decay));
+            let alpha = fixed!(1.0: I8F24) - decay;
 
             match prev_ewma {
                 Some(prev) => {
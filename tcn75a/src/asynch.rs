@@ -0,0 +1,556 @@
+/*! Async mirror of [`Tcn75a`], for use with [`embedded-hal-async`] I2C implementations (the
+pattern embassy-based HALs expose), gated behind the `async` feature.
+
+[`Tcn75aAsync`] offers [`Tcn75a`]'s `set_reg_ptr`, `temperature`, `config_reg`, `set_config_reg`,
+`limits`, `set_limits`, and `free`, with the same register-pointer/config caching semantics; it has
+no `_uncached` methods and no `shutdown`, `wake`, `one_shot`, `snapshot`, or `restore`. It reuses [`ConfigReg`], [`Limits`], and
+[`Temperature`] from the rest of the crate, so the two front-ends can't drift apart on how a
+register's bits are encoded or decoded- only the I2C plumbing underneath differs.
+
+Concretely, this lets a temperature poll `.await` instead of blocking: an `embedded-hal-async` I2C
+implementation (e.g. an embassy HAL) parks the calling task on a waker and only wakes it once the
+DMA/interrupt-driven transfer actually completes, so other tasks on the same executor keep making
+progress for the full duration of the I2C transaction instead of being stalled behind it.
+
+[`Tcn75a`]: ../struct.Tcn75a.html
+[`Tcn75aAsync`]: ./struct.Tcn75aAsync.html
+[`embedded-hal-async`]: ../embedded_hal_async/index.html
+[`ConfigReg`]: ../struct.ConfigReg.html
+[`Limits`]: ../struct.Limits.html
+[`Temperature`]: ../struct.Temperature.html
+*/
+use core::convert::TryFrom;
+use core::fmt;
+
+use embedded_hal_async::i2c::I2c;
+use fixed::types::I8F8;
+
+use crate::{ConfigReg, LimitError, Limits, Resolution, Temperature};
+
+/** Error type for [`Tcn75aAsync`] operations.
+
+This mirrors [`Tcn75aError`], except that the bus errors carry the HAL's error `E` as-is rather
+than wrapped in a [`BusError`], and there is no `PartialUpdate` variant.
+
+[`Tcn75aError`]: ../enum.Tcn75aError.html
+[`Tcn75aAsync`]: ./struct.Tcn75aAsync.html
+[`BusError`]: ../struct.BusError.html
+*/
+#[derive(Debug, PartialEq)]
+pub enum Tcn75aAsyncError<E> {
+    /** Same as [`Tcn75aError::OutOfRange`].
+
+    [`Tcn75aError::OutOfRange`]: ../enum.Tcn75aError.html#variant.OutOfRange
+    */
+    OutOfRange,
+    /** Same as [`Tcn75aError::LimitError`].
+
+    [`Tcn75aError::LimitError`]: ../enum.Tcn75aError.html#variant.LimitError
+    */
+    LimitError {
+        reason: LimitError,
+        values: (I8F8, I8F8),
+    },
+    /** Same as [`Tcn75aError::RegPtrError`].
+
+    [`Tcn75aError::RegPtrError`]: ../enum.Tcn75aError.html#variant.RegPtrError
+    */
+    RegPtrError(E),
+    /** Same as [`Tcn75aError::ReadError`].
+
+    [`Tcn75aError::ReadError`]: ../enum.Tcn75aError.html#variant.ReadError
+    */
+    ReadError(E),
+    /** Same as [`Tcn75aError::WriteError`].
+
+    [`Tcn75aError::WriteError`]: ../enum.Tcn75aError.html#variant.WriteError
+    */
+    WriteError(E),
+}
+
+impl<E: fmt::Debug> fmt::Display for Tcn75aAsyncError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tcn75aAsyncError::OutOfRange => write!(f, "temperature reading out of range"),
+            Tcn75aAsyncError::LimitError { reason: _r, values } => write!(
+                f,
+                "limit registers out of range (lo: {}, hi: {})",
+                values.0, values.1
+            ),
+            Tcn75aAsyncError::RegPtrError(_e) => write!(f, "error writing register pointer"),
+            Tcn75aAsyncError::ReadError(_e) => write!(f, "generic read error"),
+            Tcn75aAsyncError::WriteError(_e) => write!(f, "generic write error"),
+        }
+    }
+}
+
+/// Shorthand for [`Tcn75aAsyncError`] parameterized by a [`Tcn75aAsync`]'s I2C error type.
+///
+/// [`Tcn75aAsync`]: ./struct.Tcn75aAsync.html
+pub type AsyncError<T> = Tcn75aAsyncError<<T as embedded_hal_async::i2c::ErrorType>::Error>;
+
+/** Async mirror of [`Tcn75a`] for [`embedded-hal-async`] I2C implementations (for a
+single-controller I2C bus).
+
+Like [`Tcn75a`], this struct caches the register pointer and Sensor Configuration Register to
+speed up future accesses, so it's only correct on I2C buses with a single controller.
+
+[`Tcn75a`]: ../struct.Tcn75a.html
+[`embedded-hal-async`]: ../embedded_hal_async/index.html
+*/
+pub struct Tcn75aAsync<T>
+where
+    T: I2c,
+{
+    ctx: T,
+    address: u8,
+    reg: Option<u8>,
+    cfg: Option<ConfigReg>,
+}
+
+impl<T> Tcn75aAsync<T>
+where
+    T: I2c,
+{
+    /// Like [`Tcn75a::new`].
+    ///
+    /// [`Tcn75a::new`]: ../struct.Tcn75a.html#method.new
+    pub fn new(ctx: T, address: u8) -> Self {
+        Tcn75aAsync {
+            ctx,
+            address,
+            reg: None,
+            cfg: None,
+        }
+    }
+
+    /// Like [`Tcn75a::set_reg_ptr`].
+    ///
+    /// [`Tcn75a::set_reg_ptr`]: ../struct.Tcn75a.html#method.set_reg_ptr
+    pub async fn set_reg_ptr(&mut self, ptr: u8) -> Result<(), AsyncError<T>> {
+        if ptr > 3 {
+            panic!("Register pointer must be set to between 0 and 3 (inclusive).");
+        }
+
+        if let Some(curr) = self.reg {
+            if curr == ptr {
+                return Ok(());
+            }
+        }
+
+        self.ctx
+            .write(self.address, &[ptr])
+            .await
+            .map_err(|e| {
+                self.reg = None;
+                Tcn75aAsyncError::RegPtrError(e)
+            })?;
+        self.reg = Some(ptr);
+
+        Ok(())
+    }
+
+    /// Like [`Tcn75a::temperature`].
+    ///
+    /// [`Tcn75a::temperature`]: ../struct.Tcn75a.html#method.temperature
+    pub async fn temperature(&mut self) -> Result<Temperature, AsyncError<T>> {
+        let mut temp: [u8; 2] = [0u8; 2];
+
+        self.set_reg_ptr(0x00).await?;
+        self.ctx
+            .read(self.address, &mut temp)
+            .await
+            .map_err(Tcn75aAsyncError::ReadError)?;
+
+        let raw_temp = i16::from_be_bytes(temp);
+
+        let resolution = match self.cfg {
+            Some(cfg) => cfg.get_resolution(),
+            None => self
+                .config_reg()
+                .await
+                .map(|cfg| cfg.get_resolution())
+                .unwrap_or(Resolution::Bits9),
+        };
+
+        if (raw_temp & resolution.out_of_range_mask()) == 0 {
+            Ok(Temperature(I8F8::from_bits(raw_temp), resolution))
+        } else {
+            Err(Tcn75aAsyncError::OutOfRange)
+        }
+    }
+
+    /// Like [`Tcn75a::config_reg`].
+    ///
+    /// [`Tcn75a::config_reg`]: ../struct.Tcn75a.html#method.config_reg
+    pub async fn config_reg(&mut self) -> Result<ConfigReg, AsyncError<T>> {
+        let mut buf: [u8; 1] = [0u8; 1];
+
+        if let Some(curr) = self.cfg {
+            return Ok(curr);
+        }
+
+        self.set_reg_ptr(0x01).await?;
+        let cfg = self
+            .ctx
+            .read(self.address, &mut buf)
+            .await
+            .map(|_| {
+                let cfg = ConfigReg::from_bytes(buf);
+
+                self.cfg = Some(cfg);
+                cfg
+            })
+            .map_err(|e| {
+                self.cfg = None;
+                Tcn75aAsyncError::ReadError(e)
+            })?;
+
+        Ok(cfg)
+    }
+
+    /// Like [`Tcn75a::set_config_reg`].
+    ///
+    /// [`Tcn75a::set_config_reg`]: ../struct.Tcn75a.html#method.set_config_reg
+    pub async fn set_config_reg(&mut self, cfg: ConfigReg) -> Result<(), AsyncError<T>> {
+        let mut buf: [u8; 2] = [0u8; 2];
+
+        buf[0] = 0x01;
+        buf[1] = cfg.into_bytes()[0];
+
+        self.ctx
+            .write(self.address, &buf)
+            .await
+            .map(|_| {
+                self.cfg = Some(cfg);
+            })
+            .map_err(|e| {
+                self.reg = None;
+                self.cfg = None;
+                Tcn75aAsyncError::WriteError(e)
+            })?;
+        self.reg = Some(0x01);
+
+        Ok(())
+    }
+
+    /// Like [`Tcn75a::limits`].
+    ///
+    /// [`Tcn75a::limits`]: ../struct.Tcn75a.html#method.limits
+    pub async fn limits(&mut self) -> Result<Limits, AsyncError<T>> {
+        let mut buf: [u8; 2] = [0u8; 2];
+        let mut lim: (I8F8, I8F8) = (0.into(), 0.into());
+
+        self.set_reg_ptr(0x02).await?;
+        lim.0 = self
+            .ctx
+            .read(self.address, &mut buf)
+            .await
+            .map(|_| I8F8::from_be_bytes(buf))
+            .map_err(Tcn75aAsyncError::ReadError)?;
+
+        self.set_reg_ptr(0x03).await?;
+        lim.1 = self
+            .ctx
+            .read(self.address, &mut buf)
+            .await
+            .map(|_| I8F8::from_be_bytes(buf))
+            .map_err(Tcn75aAsyncError::ReadError)?;
+
+        TryFrom::try_from(lim).map_err(|r| Tcn75aAsyncError::LimitError {
+            reason: r,
+            values: lim,
+        })
+    }
+
+    /// Like [`Tcn75a::set_limits`], except that a failed Limit-Set write is returned as
+    /// [`Tcn75aAsyncError::WriteError`] with no rollback write, so the Hysteresis register may
+    /// already hold its new value.
+    ///
+    /// [`Tcn75a::set_limits`]: ../struct.Tcn75a.html#method.set_limits
+    /// [`Tcn75aAsyncError::WriteError`]: ./enum.Tcn75aAsyncError.html#variant.WriteError
+    pub async fn set_limits(&mut self, limits: Limits) -> Result<(), AsyncError<T>> {
+        let mut buf: [u8; 3] = [0u8; 3];
+        let (lower, upper): (I8F8, I8F8) = limits.into();
+
+        buf[0] = 0x02;
+        buf[1..3].copy_from_slice(&lower.to_be_bytes());
+        self.ctx.write(self.address, &buf).await.map_err(|e| {
+            self.reg = None;
+            Tcn75aAsyncError::WriteError(e)
+        })?;
+        self.reg = Some(0x02);
+
+        buf[0] = 0x03;
+        buf[1..3].copy_from_slice(&upper.to_be_bytes());
+        self.ctx.write(self.address, &buf).await.map_err(|e| {
+            self.reg = None;
+            Tcn75aAsyncError::WriteError(e)
+        })?;
+        self.reg = Some(0x03);
+
+        Ok(())
+    }
+
+    /// Like [`Tcn75a::free`].
+    ///
+    /// [`Tcn75a::free`]: ../struct.Tcn75a.html#method.free
+    pub fn free(self) -> T {
+        self.ctx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::collections::VecDeque;
+    use std::vec;
+    use std::vec::Vec;
+
+    use super::*;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    use embedded_hal_async::i2c::{ErrorType, Operation};
+
+    /// Error type for [`FakeAsyncI2c`]- there's only ever one reason a fake transaction fails.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct FakeI2cError;
+
+    #[derive(Debug, PartialEq)]
+    enum Expect {
+        Write { address: u8, bytes: Vec<u8> },
+        WriteErr { address: u8, bytes: Vec<u8> },
+        Read { address: u8, bytes: Vec<u8> },
+    }
+
+    // A transaction-queue fake, in the same spirit as `embedded_hal_mock::i2c::Mock`, but for
+    // `embedded_hal_async::i2c::I2c`, which there's no mock crate support for yet.
+    struct FakeAsyncI2c {
+        expectations: VecDeque<Expect>,
+    }
+
+    impl FakeAsyncI2c {
+        fn new(expectations: Vec<Expect>) -> Self {
+            FakeAsyncI2c {
+                expectations: expectations.into(),
+            }
+        }
+    }
+
+    impl Drop for FakeAsyncI2c {
+        fn drop(&mut self) {
+            assert!(
+                self.expectations.is_empty(),
+                "unconsumed expectations: {:?}",
+                self.expectations
+            );
+        }
+    }
+
+    impl ErrorType for FakeAsyncI2c {
+        type Error = FakeI2cError;
+    }
+
+    impl I2c for FakeAsyncI2c {
+        async fn transaction(
+            &mut self,
+            _address: u8,
+            _operations: &mut [Operation<'_>],
+        ) -> Result<(), Self::Error> {
+            unimplemented!("Tcn75aAsync never issues a transaction() call")
+        }
+
+        async fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+            match self.expectations.pop_front() {
+                Some(Expect::Write { address: a, bytes: b }) if a == address && b == bytes => {
+                    Ok(())
+                }
+                Some(Expect::WriteErr { address: a, bytes: b }) if a == address && b == bytes => {
+                    Err(FakeI2cError)
+                }
+                other => panic!("unexpected write({:#x}, {:?}); next was {:?}", address, bytes, other),
+            }
+        }
+
+        async fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+            match self.expectations.pop_front() {
+                Some(Expect::Read { address: a, bytes: b })
+                    if a == address && b.len() == buffer.len() =>
+                {
+                    buffer.copy_from_slice(&b);
+                    Ok(())
+                }
+                other => panic!(
+                    "unexpected read({:#x}, len {}); next was {:?}",
+                    address,
+                    buffer.len(),
+                    other
+                ),
+            }
+        }
+    }
+
+    fn noop(_: *const ()) {}
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    // `Tcn75aAsync`'s futures never actually suspend against our `FakeAsyncI2c` (every operation
+    // resolves on first poll), so a full executor is overkill- just poll to completion.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = fut;
+        // SAFETY: `fut` is shadowed here and never moved again.
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+        loop {
+            if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+                return val;
+            }
+        }
+    }
+
+    #[test]
+    fn temperature_roundtrip() {
+        let i2c = FakeAsyncI2c::new(vec![
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![0],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x7f, 0x80],
+            },
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![1],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x00],
+            },
+        ]);
+        let mut tcn = Tcn75aAsync::new(i2c, 0x48);
+
+        let temp = block_on(tcn.temperature());
+        assert!(temp.is_ok());
+        assert_eq!(
+            I8F8::from(temp.unwrap()),
+            I8F8::from_bits(((127 << 4) + 8) << 4)
+        );
+    }
+
+    #[test]
+    fn config_reg_cached() {
+        let i2c = FakeAsyncI2c::new(vec![
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![1],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x60],
+            },
+        ]);
+        let mut tcn = Tcn75aAsync::new(i2c, 0x48);
+
+        let cfg = block_on(tcn.config_reg());
+        assert!(cfg.is_ok());
+        assert_eq!(cfg.unwrap().get_resolution(), Resolution::Bits12);
+
+        // Cached- no further I2C transactions expected.
+        let cfg = block_on(tcn.config_reg());
+        assert_eq!(cfg.unwrap().get_resolution(), Resolution::Bits12);
+    }
+
+    #[test]
+    fn set_limits_then_read_back() {
+        use core::convert::TryInto;
+        use fixed_macro::fixed;
+
+        let i2c = FakeAsyncI2c::new(vec![
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![2, 0x19, 0x00],
+            },
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![3, 0x1e, 0x00],
+            },
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![2],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x19, 0x00],
+            },
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![3],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x1e, 0x00],
+            },
+        ]);
+        let mut tcn = Tcn75aAsync::new(i2c, 0x48);
+
+        let limits = (fixed!(25.0: I8F8), fixed!(30.0: I8F8)).try_into().unwrap();
+        assert_eq!(block_on(tcn.set_limits(limits)), Ok(()));
+        assert_eq!(
+            block_on(tcn.limits()).unwrap(),
+            limits
+        );
+    }
+
+    #[test]
+    fn config_reg_reg_ptr_error() {
+        let i2c = FakeAsyncI2c::new(vec![Expect::WriteErr {
+            address: 0x48,
+            bytes: vec![1],
+        }]);
+        let mut tcn = Tcn75aAsync::new(i2c, 0x48);
+
+        assert_eq!(
+            block_on(tcn.config_reg()),
+            Err(Tcn75aAsyncError::RegPtrError(FakeI2cError))
+        );
+    }
+
+    #[test]
+    fn limits_rejects_swapped_values() {
+        let i2c = FakeAsyncI2c::new(vec![
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![2],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x1e, 0x00],
+            },
+            Expect::Write {
+                address: 0x48,
+                bytes: vec![3],
+            },
+            Expect::Read {
+                address: 0x48,
+                bytes: vec![0x19, 0x00],
+            },
+        ]);
+        let mut tcn = Tcn75aAsync::new(i2c, 0x48);
+        use fixed_macro::fixed;
+
+        assert_eq!(
+            block_on(tcn.limits()),
+            Err(Tcn75aAsyncError::LimitError {
+                reason: LimitError::LowExceedsHigh,
+                values: (fixed!(30.0: I8F8), fixed!(25.0: I8F8)),
+            })
+        );
+    }
+}
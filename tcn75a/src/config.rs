@@ -1,5 +1,6 @@
 use modular_bitfield::prelude::*;
 use core::convert::{From, TryFrom};
+use core::fmt;
 
 /** Representation of the Sensor Configuration Register.
 
@@ -54,18 +55,17 @@ pub struct ConfigReg {
     one_shot: OneShot,
 }
 
-/** Error type due to failed conversions from u8 into Configuration Register fields.
-
-This type cannot be created by the user. The main use of this type is to handle invalid
-user-supplied config register values for the [`Resolution`] and [`FaultQueue`] Configuration
-Registers fields.:
+/** Error type due to failed conversions into Configuration Register fields, whether from a raw
+`u8` (the [`Resolution`]/[`FaultQueue`] `TryFrom<u8>` impls) or from the text of a `key=value`
+config line (see [`ConfigReg::from_config_str`]). `key` and `value` identify the offending
+`key=value` pair, so a malformed line can be reported instead of silently ignored.
 
 ```
 # use std::convert::Into;
 # use std::convert::TryInto;
 # use tcn75a::Resolution;
 # use tcn75a::ConfigRegValueError;
-fn main() -> Result<(), ConfigRegValueError> {
+fn main() -> Result<(), ConfigRegValueError<'static>> {
     let res: Resolution = 9.try_into()?; // Fake user-supplied input. Always succeeds.
     Ok(())
 }
@@ -73,9 +73,16 @@ fn main() -> Result<(), ConfigRegValueError> {
 
 [`Resolution`]: ./enum.Resolution.html
 [`FaultQueue`]: ./enum.FaultQueue.html
+[`ConfigReg::from_config_str`]: ./struct.ConfigReg.html#method.from_config_str
 */
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct ConfigRegValueError(());
+pub struct ConfigRegValueError<'a> {
+    /// The `key=value` key whose value failed to parse (e.g. `"resolution"`). `None` for the
+    /// plain `u8` -> enum conversions below, which have no key context.
+    pub key: Option<&'a str>,
+    /// The value text that failed to parse. Empty for the plain `u8` -> enum conversions.
+    pub value: &'a str,
+}
 
 /** One-Shot bit in the Sensor Configuration Register.
 
@@ -91,6 +98,33 @@ pub enum OneShot {
     Enabled,
 }
 
+impl fmt::Display for OneShot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OneShot::Disabled => write!(f, "disabled"),
+            OneShot::Enabled => write!(f, "enabled"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for OneShot {
+    type Error = ConfigRegValueError<'a>;
+
+    /// Parses a `one_shot` config value (`"disabled"` or `"enabled"`, case-insensitive).
+    fn try_from(value: &'a str) -> Result<OneShot, Self::Error> {
+        if value.eq_ignore_ascii_case("disabled") {
+            Ok(OneShot::Disabled)
+        } else if value.eq_ignore_ascii_case("enabled") {
+            Ok(OneShot::Enabled)
+        } else {
+            Err(ConfigRegValueError {
+                key: Some("one_shot"),
+                value,
+            })
+        }
+    }
+}
+
 /** ADC Resolution bits in the Sensor Configuration Register.
 
 Consult the TCN75A [datasheet] for information on the meanings of each variant.
@@ -107,7 +141,7 @@ vice-versa using [`TryFrom<u8>`][`TryFrom`] and [`From<Resolution>`][`From`] res
 # use tcn75a::ConfigRegValueError;
 let res: Resolution = 9u8.try_into().unwrap();
 let res_as_int: u8 = Resolution::Bits10.into();
-let try_res_fail: Result<Resolution, ConfigRegValueError> = 13u8.try_into();
+let try_res_fail: Result<Resolution, ConfigRegValueError<'static>> = 13u8.try_into();
 
 assert_eq!(res, Resolution::Bits9);
 assert_eq!(res_as_int, 10u8);
@@ -119,7 +153,7 @@ assert!(try_res_fail.is_err());
 [`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
 [`From`]: https://doc.rust-lang.org/nightly/core/convert/trait.From.html
 */
-#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq)]
 pub enum Resolution {
     Bits9 = 0,
     Bits10,
@@ -127,6 +161,23 @@ pub enum Resolution {
     Bits12,
 }
 
+impl Resolution {
+    /** Bits of a 16-bit ambient-temperature reading that must read zero at this resolution (the
+    low, unused fraction bits). A masked bit that's set indicates the reading doesn't actually
+    match this resolution- e.g. 12-bit-precision bits set while configured for [`Bits9`].
+
+    [`Bits9`]: ./enum.Resolution.html#variant.Bits9
+    */
+    pub(crate) fn out_of_range_mask(self) -> i16 {
+        match self {
+            Resolution::Bits9 => 0x007F,
+            Resolution::Bits10 => 0x003F,
+            Resolution::Bits11 => 0x001F,
+            Resolution::Bits12 => 0x000F,
+        }
+    }
+}
+
 impl From<Resolution> for u8 {
     fn from(res: Resolution) -> u8 {
         match res {
@@ -138,8 +189,8 @@ impl From<Resolution> for u8 {
     }
 }
 
-impl TryFrom<u8> for Resolution {
-    type Error = ConfigRegValueError;
+impl<'a> TryFrom<u8> for Resolution {
+    type Error = ConfigRegValueError<'a>;
 
     fn try_from(value: u8) -> Result<Resolution, Self::Error> {
         match value {
@@ -147,11 +198,41 @@ impl TryFrom<u8> for Resolution {
             10 => Ok(Resolution::Bits10),
             11 => Ok(Resolution::Bits11),
             12 => Ok(Resolution::Bits12),
-            _ => Err(ConfigRegValueError(())),
+            _ => Err(ConfigRegValueError {
+                key: None,
+                value: "",
+            }),
         }
     }
 }
 
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Resolution {
+    type Error = ConfigRegValueError<'a>;
+
+    /** Parses a `resolution` config value (`"9"`, `"10"`, `"11"`, or `"12"`) into a
+    [`Resolution`], via [`TryFrom<u8>`][`TryFrom`].
+
+    [`Resolution`]: ./enum.Resolution.html
+    [`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+    */
+    fn try_from(value: &'a str) -> Result<Resolution, Self::Error> {
+        value
+            .parse::<u8>()
+            .ok()
+            .and_then(|v| Resolution::try_from(v).ok())
+            .ok_or(ConfigRegValueError {
+                key: Some("resolution"),
+                value,
+            })
+    }
+}
+
 /** Fault Queue bits in the Sensor Configuration Register.
 
 Consult the TCN75A [datasheet] for information on the meanings of each variant.
@@ -168,7 +249,7 @@ vice-versa using [`TryFrom<u8>`][`TryFrom`] and [`From<FaultQueue>`][`From`] res
 # use tcn75a::ConfigRegValueError;
 let fq: FaultQueue = 1u8.try_into().unwrap();
 let fq_as_int: u8 = FaultQueue::Two.into();
-let try_fq_fail: Result<FaultQueue, ConfigRegValueError> = 8u8.try_into();
+let try_fq_fail: Result<FaultQueue, ConfigRegValueError<'static>> = 8u8.try_into();
 
 assert_eq!(fq, FaultQueue::One);
 assert_eq!(fq_as_int, 2u8);
@@ -201,8 +282,8 @@ impl From<FaultQueue> for u8 {
     }
 }
 
-impl TryFrom<u8> for FaultQueue {
-    type Error = ConfigRegValueError;
+impl<'a> TryFrom<u8> for FaultQueue {
+    type Error = ConfigRegValueError<'a>;
 
     fn try_from(value: u8) -> Result<FaultQueue, Self::Error> {
         match value {
@@ -210,11 +291,47 @@ impl TryFrom<u8> for FaultQueue {
             2 => Ok(FaultQueue::Two),
             4 => Ok(FaultQueue::Four),
             6 => Ok(FaultQueue::Six),
-            _ => Err(ConfigRegValueError(())),
+            _ => Err(ConfigRegValueError {
+                key: None,
+                value: "",
+            }),
         }
     }
 }
 
+impl fmt::Display for FaultQueue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value: u8 = match self {
+            FaultQueue::One => 1,
+            FaultQueue::Two => 2,
+            FaultQueue::Four => 4,
+            FaultQueue::Six => 6,
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FaultQueue {
+    type Error = ConfigRegValueError<'a>;
+
+    /** Parses a `fault_queue` config value (`"1"`, `"2"`, `"4"`, or `"6"`) into a
+    [`FaultQueue`], via [`TryFrom<u8>`][`TryFrom`].
+
+    [`FaultQueue`]: ./enum.FaultQueue.html
+    [`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+    */
+    fn try_from(value: &'a str) -> Result<FaultQueue, Self::Error> {
+        value
+            .parse::<u8>()
+            .ok()
+            .and_then(|v| FaultQueue::try_from(v).ok())
+            .ok_or(ConfigRegValueError {
+                key: Some("fault_queue"),
+                value,
+            })
+    }
+}
+
 /** Alert Polarity bit in the Sensor Configuration Register.
 
 Consult the TCN75A [datasheet] for information on the meanings of each variant.
@@ -229,6 +346,33 @@ pub enum AlertPolarity {
     ActiveHigh,
 }
 
+impl fmt::Display for AlertPolarity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlertPolarity::ActiveLow => write!(f, "low"),
+            AlertPolarity::ActiveHigh => write!(f, "high"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for AlertPolarity {
+    type Error = ConfigRegValueError<'a>;
+
+    /// Parses an `alert_polarity` config value (`"low"` or `"high"`, case-insensitive).
+    fn try_from(value: &'a str) -> Result<AlertPolarity, Self::Error> {
+        if value.eq_ignore_ascii_case("low") {
+            Ok(AlertPolarity::ActiveLow)
+        } else if value.eq_ignore_ascii_case("high") {
+            Ok(AlertPolarity::ActiveHigh)
+        } else {
+            Err(ConfigRegValueError {
+                key: Some("alert_polarity"),
+                value,
+            })
+        }
+    }
+}
+
 /** Comp/Int bit in the Sensor Configuration Register.
 
 Consult the TCN75A [datasheet] for information on the meanings of each variant.
@@ -243,6 +387,33 @@ pub enum CompInt {
     Interrupt,
 }
 
+impl fmt::Display for CompInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompInt::Comparator => write!(f, "comparator"),
+            CompInt::Interrupt => write!(f, "interrupt"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CompInt {
+    type Error = ConfigRegValueError<'a>;
+
+    /// Parses a `comp_int` config value (`"comparator"` or `"interrupt"`, case-insensitive).
+    fn try_from(value: &'a str) -> Result<CompInt, Self::Error> {
+        if value.eq_ignore_ascii_case("comparator") {
+            Ok(CompInt::Comparator)
+        } else if value.eq_ignore_ascii_case("interrupt") {
+            Ok(CompInt::Interrupt)
+        } else {
+            Err(ConfigRegValueError {
+                key: Some("comp_int"),
+                value,
+            })
+        }
+    }
+}
+
 /** Shutdown bit in the Sensor Configuration Register.
 
 Consult the TCN75A [datasheet] for information on the meanings of each variant.
@@ -257,6 +428,118 @@ pub enum Shutdown {
     Enable,
 }
 
+impl fmt::Display for Shutdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shutdown::Disable => write!(f, "disable"),
+            Shutdown::Enable => write!(f, "enable"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Shutdown {
+    type Error = ConfigRegValueError<'a>;
+
+    /// Parses a `shutdown` config value (`"disable"` or `"enable"`, case-insensitive).
+    fn try_from(value: &'a str) -> Result<Shutdown, Self::Error> {
+        if value.eq_ignore_ascii_case("disable") {
+            Ok(Shutdown::Disable)
+        } else if value.eq_ignore_ascii_case("enable") {
+            Ok(Shutdown::Enable)
+        } else {
+            Err(ConfigRegValueError {
+                key: Some("shutdown"),
+                value,
+            })
+        }
+    }
+}
+
+impl ConfigReg {
+    /** Parses a newline-delimited `key=value` config text into a [`ConfigReg`], starting from
+    [`ConfigReg::new`]'s reset defaults for any key that isn't present.
+
+    This mirrors the `key=value`-per-line config file convention used elsewhere in this project
+    (see the `i2c-server` crate's own config file), but unlike that parser, a malformed value is
+    reported rather than silently dropped- so a typo in a flash/SD-card-resident config doesn't
+    silently leave the device in an unexpected state. Blank lines and lines starting with `#` are
+    ignored, as are keys this function doesn't recognize (so a [`ConfigReg`]'s `key=value` lines
+    can share a config file with settings for unrelated parts of a system).
+
+    Recognized keys: `shutdown`, `comp_int`, `alert_polarity`, `fault_queue`, `resolution`,
+    `one_shot`. See each field enum's [`TryFrom<&str>`][`TryFrom`] impl for the accepted value
+    text.
+
+    # Examples
+
+    ```
+    # use tcn75a::{ConfigReg, Resolution, CompInt};
+    let cfg = ConfigReg::from_config_str(
+        "resolution=12\ncomp_int=interrupt\n# a comment\n\nunknown_key=ignored\n",
+    ).unwrap();
+
+    assert_eq!(cfg.get_resolution(), Resolution::Bits12);
+    assert_eq!(cfg.get_comp_int(), CompInt::Interrupt);
+    ```
+
+    # Errors
+
+    Returns a [`ConfigRegValueError`] naming the offending `key` and `value` text as soon as one
+    of the recognized keys has a value that fails to parse.
+
+    [`ConfigReg`]: ./struct.ConfigReg.html
+    [`ConfigReg::new`]: ./struct.ConfigReg.html#method.new
+    [`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+    [`ConfigRegValueError`]: ./struct.ConfigRegValueError.html
+    */
+    pub fn from_config_str(text: &str) -> Result<ConfigReg, ConfigRegValueError<'_>> {
+        let mut cfg = ConfigReg::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = match parts.next() {
+                Some(v) => v.trim(),
+                None => continue,
+            };
+
+            match key {
+                "shutdown" => cfg.set_shutdown(Shutdown::try_from(value)?),
+                "comp_int" => cfg.set_comp_int(CompInt::try_from(value)?),
+                "alert_polarity" => cfg.set_alert_polarity(AlertPolarity::try_from(value)?),
+                "fault_queue" => cfg.set_fault_queue(FaultQueue::try_from(value)?),
+                "resolution" => cfg.set_resolution(Resolution::try_from(value)?),
+                "one_shot" => cfg.set_one_shot(OneShot::try_from(value)?),
+                _ => {}
+            }
+        }
+
+        Ok(cfg)
+    }
+
+    /** Serializes this [`ConfigReg`] back into the `key=value`-per-line text [`from_config_str`]
+    parses, writing one `key=value\n` line per field through `w`.
+
+    [`ConfigReg`]: ./struct.ConfigReg.html
+    [`from_config_str`]: ./struct.ConfigReg.html#method.from_config_str
+    */
+    pub fn write_config_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "shutdown={}", self.get_shutdown())?;
+        writeln!(w, "comp_int={}", self.get_comp_int())?;
+        writeln!(w, "alert_polarity={}", self.get_alert_polarity())?;
+        writeln!(w, "fault_queue={}", self.get_fault_queue())?;
+        writeln!(w, "resolution={}", self.get_resolution())?;
+        writeln!(w, "one_shot={}", self.get_one_shot())?;
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,4 +586,100 @@ mod tests {
         let val = u8::from_le_bytes(cfg.to_bytes().try_into().unwrap());
         assert_eq!(val, 0);
     }
+
+    #[test]
+    fn test_enum_from_str() {
+        assert_eq!(Shutdown::try_from("disable").unwrap(), Shutdown::Disable);
+        assert_eq!(Shutdown::try_from("ENABLE").unwrap(), Shutdown::Enable);
+        assert_eq!(CompInt::try_from("Interrupt").unwrap(), CompInt::Interrupt);
+        assert_eq!(
+            AlertPolarity::try_from("HIGH").unwrap(),
+            AlertPolarity::ActiveHigh
+        );
+        assert_eq!(Resolution::try_from("12").unwrap(), Resolution::Bits12);
+        assert_eq!(FaultQueue::try_from("6").unwrap(), FaultQueue::Six);
+        assert_eq!(OneShot::try_from("enabled").unwrap(), OneShot::Enabled);
+    }
+
+    #[test]
+    fn test_enum_from_str_invalid() {
+        let err = Resolution::try_from("13").unwrap_err();
+        assert_eq!(err.key, Some("resolution"));
+        assert_eq!(err.value, "13");
+
+        let err = Shutdown::try_from("maybe").unwrap_err();
+        assert_eq!(err.key, Some("shutdown"));
+        assert_eq!(err.value, "maybe");
+    }
+
+    #[test]
+    fn test_from_config_str() {
+        let cfg = ConfigReg::from_config_str(
+            "shutdown=enable\n\
+             comp_int=interrupt\n\
+             # a comment, then a blank line\n\
+             \n\
+             alert_polarity=high\n\
+             fault_queue=6\n\
+             resolution=12\n\
+             one_shot=enabled\n\
+             unknown_key=ignored\n",
+        )
+        .unwrap();
+
+        assert_eq!(cfg.get_shutdown(), Shutdown::Enable);
+        assert_eq!(cfg.get_comp_int(), CompInt::Interrupt);
+        assert_eq!(cfg.get_alert_polarity(), AlertPolarity::ActiveHigh);
+        assert_eq!(cfg.get_fault_queue(), FaultQueue::Six);
+        assert_eq!(cfg.get_resolution(), Resolution::Bits12);
+        assert_eq!(cfg.get_one_shot(), OneShot::Enabled);
+    }
+
+    #[test]
+    fn test_from_config_str_invalid_value() {
+        let err = ConfigReg::from_config_str("resolution=13\n").unwrap_err();
+        assert_eq!(err.key, Some("resolution"));
+        assert_eq!(err.value, "13");
+    }
+
+    struct FixedBuf {
+        buf: [u8; 128],
+        len: usize,
+    }
+
+    impl FixedBuf {
+        fn new() -> Self {
+            FixedBuf {
+                buf: [0; 128],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for FixedBuf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let bytes = s.as_bytes();
+            self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            self.len += bytes.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_config_str_roundtrip() {
+        let mut cfg = ConfigReg::new();
+        cfg.set_resolution(Resolution::Bits12);
+        cfg.set_fault_queue(FaultQueue::Six);
+        cfg.set_comp_int(CompInt::Interrupt);
+
+        let mut out = FixedBuf::new();
+        cfg.write_config_str(&mut out).unwrap();
+
+        let reparsed = ConfigReg::from_config_str(out.as_str()).unwrap();
+        assert_eq!(reparsed, cfg);
+    }
 }
@@ -14,9 +14,46 @@ The remaining register contains the current temperature as an [`FixedI16::<U8>`]
 from -128.0 to 127.9375 (variable increments based on [`Resolution`]).
 
 To avoid redundant register reads and write, the `tcn75a` crate caches the contents of some
-registers (particularly the register pointer and Sensor Configuration Register). At present,
-the `tcn75a` crate therefore _only works on I2C buses with a single controller._ Multi-controller
-operation is possible at the cost of performance, but not implemented.
+registers (particularly the register pointer and Sensor Configuration Register). When the
+register pointer cache is cold (or was flushed by a previous error), setting it and reading the
+newly-pointed-to register happens as a single atomic [`I2c::write_read`] (a repeated-START), so
+another controller on the bus cannot move the pointer in between; once the pointer is known-good,
+later reads of the same register are a lone [`I2c::read`], which is already atomic. This fast,
+cached path is therefore _only correct on I2C buses with a single controller_ — another
+controller could still move the register pointer out from under the cache between calls.
+
+For multi-controller buses, the `_uncached` methods ([`temperature_uncached`],
+[`config_reg_uncached`], [`limits_uncached`]) set the register pointer and read the
+register's contents as one atomic I2C transaction on _every_ call, and never touch
+the `reg`/`cfg` caches.
+
+[`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+[`I2c::read`]: ../embedded_hal/i2c/trait.I2c.html#method.read
+
+[`temperature_uncached`]: ./struct.Tcn75a.html#method.temperature_uncached
+[`config_reg_uncached`]: ./struct.Tcn75a.html#method.config_reg_uncached
+[`limits_uncached`]: ./struct.Tcn75a.html#method.limits_uncached
+
+For battery-powered designs, [`shutdown`]/[`wake`] toggle the SHUTDOWN bit to park the TCN75A at
+microamp idle current, and [`one_shot`] takes a single reading without leaving shutdown at all
+(useful for periodic sampling).
+
+[`shutdown`]: ./struct.Tcn75a.html#method.shutdown
+[`wake`]: ./struct.Tcn75a.html#method.wake
+[`one_shot`]: ./struct.Tcn75a.html#method.one_shot
+
+With the `async` feature enabled, [`asynch::Tcn75aAsync`] offers an `.await`-able mirror of this
+driver for [`embedded-hal-async`] I2C implementations, so a temperature poll doesn't block an
+executor while other tasks could be making progress.
+
+[`asynch::Tcn75aAsync`]: ./asynch/struct.Tcn75aAsync.html
+[`embedded-hal-async`]: ../embedded_hal_async/index.html
+
+[`Thermostat`] packages the ALERT pin's comparator/interrupt mode, polarity, fault queue, and
+limits knobs into a single thermal-watchdog workflow, instead of making users hand-assemble config
+bits and limit registers.
+
+[`Thermostat`]: ./struct.Thermostat.html
 
 [Embedded HAL]: https://github.com/rust-embedded/embedded-hal
 [TCN75A]: https://www.microchip.com/wwwproducts/TCN75A
@@ -30,7 +67,8 @@ operation is possible at the cost of performance, but not implemented.
 use core::convert::TryFrom;
 use core::fmt;
 use core::result::Result;
-use embedded_hal::blocking::i2c::{Read, Write};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{self, ErrorType, I2c};
 use fixed::types::I8F8;
 
 mod config;
@@ -42,6 +80,17 @@ pub use limit::*;
 mod temp;
 pub use temp::*;
 
+mod state;
+pub use state::*;
+
+#[cfg(feature = "async")]
+mod asynch;
+#[cfg(feature = "async")]
+pub use asynch::*;
+
+mod thermostat;
+pub use thermostat::*;
+
 /** A struct for describing how to read and write a TCN75A temperature sensors' registers via an
 [`embedded_hal`] implementation (for a single-controller I2C bus).
 
@@ -54,7 +103,7 @@ controller.
 */
 pub struct Tcn75a<T>
 where
-    T: Read + Write,
+    T: I2c,
 {
     ctx: T,
     address: u8,
@@ -64,7 +113,7 @@ where
 
 impl<T> fmt::Debug for Tcn75a<T>
 where
-    T: Read + Write,
+    T: I2c,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Tcn75a")
@@ -76,12 +125,124 @@ where
     }
 }
 
-/// Enum for describing possible error conditions when reading/writing a TCN75A temperature sensor.
-pub enum Tcn75aError<R, W>
+/** A coarse classification of why an I2C bus transaction failed, borrowed from the `AbortReason`
+taxonomy embassy's RP/STM32 I2C drivers use.
+
+Unlike [`embedded_hal::i2c::ErrorKind`], this distinguishes a NACK on the address byte (no device
+answered at that address) from a NACK on a data byte (a device answered, but then refused a
+byte), since the former usually means "wrong/absent address" and the latter "bus protocol or
+device-state problem" — different things to retry on a contended multi-master bus.
+
+[`embedded_hal::i2c::ErrorKind`]: ../embedded_hal/i2c/enum.ErrorKind.html
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BusErrorKind {
+    /// No device acknowledged the address byte; nothing is listening at this address.
+    AddressNack,
+    /// A device acknowledged the address, but then NACKed a data byte.
+    DataNack,
+    /// Arbitration was lost to another controller on the bus; the transaction can be retried.
+    ArbitrationLoss,
+    /// Some other bus fault (overrun, bus error, or a HAL-specific condition).
+    Other,
+}
+
+impl From<i2c::ErrorKind> for BusErrorKind {
+    fn from(kind: i2c::ErrorKind) -> Self {
+        match kind {
+            i2c::ErrorKind::NoAcknowledge(i2c::NoAcknowledgeSource::Address) => {
+                BusErrorKind::AddressNack
+            }
+            i2c::ErrorKind::NoAcknowledge(_) => BusErrorKind::DataNack,
+            i2c::ErrorKind::ArbitrationLoss => BusErrorKind::ArbitrationLoss,
+            _ => BusErrorKind::Other,
+        }
+    }
+}
+
+/** A bus error annotated with a [`BusErrorKind`] classification, so callers don't have to
+inspect the underlying HAL error themselves to decide whether a retry is sensible.
+
+`source` is the original HAL error `Tcn75a` received; `kind` is derived from it via
+[`embedded_hal::i2c::Error::kind`].
+
+[`embedded_hal::i2c::Error::kind`]: ../embedded_hal/i2c/trait.Error.html#tymethod.kind
+*/
+pub struct BusError<E> {
+    pub kind: BusErrorKind,
+    pub source: E,
+}
+
+impl<E> From<E> for BusError<E>
+where
+    E: i2c::Error,
+{
+    fn from(source: E) -> Self {
+        BusError {
+            kind: source.kind().into(),
+            source,
+        }
+    }
+}
+
+impl<E> fmt::Debug for BusError<E>
 where
-    R: Read,
-    W: Write,
+    E: fmt::Debug,
 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BusError")
+            .field("kind", &self.kind)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<E> PartialEq for BusError<E>
+where
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.source == other.source
+    }
+}
+
+impl<E> Eq for BusError<E> where E: Eq {}
+
+impl<E> Clone for BusError<E>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        BusError {
+            kind: self.kind,
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl<E> Copy for BusError<E> where E: Copy {}
+
+/** Identifies one of the two registers a [`Limits`] pair is written across.
+
+[`Limits`]: ./struct.Limits.html
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RegId {
+    /// The Hysteresis (lower) register.
+    Hysteresis,
+    /// The Limit-Set (upper) register.
+    LimitSet,
+}
+
+/** Enum for describing possible error conditions when reading/writing a TCN75A temperature sensor.
+
+[`embedded_hal::i2c::I2c`] exposes a single associated `Error` type shared by every bus operation
+(unlike the old `embedded-hal` 0.2 `Read`/`Write` traits' separate `Error` types), so there's only
+one error type parameter here, `E`.
+
+[`embedded_hal::i2c::I2c`]: ../embedded_hal/i2c/trait.I2c.html
+*/
+pub enum Tcn75aError<E> {
     /** A temperature value was read successfully, but some bits were set that should always
     read as zero. This _may_ indicate that you are not reading a TCN75A.  */
     OutOfRange,
@@ -97,151 +258,162 @@ where
         reason: LimitError,
         values: (I8F8, I8F8),
     },
-    /** The register pointer could not be set to _read_ the desired register. Contains the error
-    reason from [`Write::Error`]. For register writes, [`WriteError`] is returned if the register
-    pointer failed to update.
+    /** The register pointer could not be set to _read_ the desired register. Contains a
+    [`BusError`] classifying the underlying bus fault. For register writes, [`WriteError`] is
+    returned if the register pointer failed to update.
 
-    [`Write::Error`]: ../embedded_hal/blocking/i2c/trait.Write.html#associatedtype.Error
+    [`BusError`]: ./struct.BusError.html
     [`WriteError`]: ./enum.Tcn75aError.html#variant.WriteError
     */
-    RegPtrError(<W as Write>::Error),
-    /** Reading the desired register via [`embedded_hal`] failed. Contains a [`Read::Error`],
-    propagated from the [`embedded_hal`] implementation.
+    RegPtrError(BusError<E>),
+    /** Reading the desired register via [`embedded_hal`] failed. Contains a [`BusError`]
+    classifying the underlying bus fault. Also returned if a combined [`I2c::write_read`]
+    (pointer-set-then-read in a single atomic transaction) failed, since the two phases can't be
+    distinguished from the caller's side of a single bus error.
 
-    [`Read::Error`]: ../embedded_hal/blocking/i2c/trait.Read.html#associatedtype.Error
     [`embedded_hal`]: ../embedded_hal/index.html
+    [`BusError`]: ./struct.BusError.html
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
     */
-    ReadError(<R as Read>::Error),
-    /** Writing the desired register via [`embedded_hal`] failed. Contains a [`Write::Error`],
-    propagated from the [`embedded_hal`] implementation.
+    ReadError(BusError<E>),
+    /** Writing the desired register via [`embedded_hal`] failed. Contains a [`BusError`]
+    classifying the underlying bus fault.
 
-    [`Write::Error`]: ../embedded_hal/blocking/i2c/trait.Write.html#associatedtype.Error
     [`embedded_hal`]: ../embedded_hal/index.html
+    [`BusError`]: ./struct.BusError.html
+    */
+    WriteError(BusError<E>),
+    /** [`set_limits`] committed only one of the Hysteresis/Limit-Set register writes before the
+    other failed, which can leave the device in a state that violates the Hysteresis-less-than-
+    Limit-Set [invariant]. `written` is the register that was actually updated on the device
+    (`source` is the bus error from the _other_ write); a rollback write was attempted to force
+    the pair back into a consistent (if not the originally-requested) state, but its own success
+    or failure isn't reported here- if the device's state matters, re-read it via [`limits`].
+
+    [`set_limits`]: ./struct.Tcn75a.html#method.set_limits
+    [`limits`]: ./struct.Tcn75a.html#method.limits
+    [invariant]: ./struct.Limits.html#invariants
     */
-    WriteError(<W as Write>::Error),
+    PartialUpdate { written: RegId, source: E },
 }
 
-impl<R, W> fmt::Display for Tcn75aError<R, W>
-where
-    R: Read,
-    W: Write,
-{
+impl<E> fmt::Display for Tcn75aError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Tcn75aError::<R, W>::OutOfRange => write!(f, "temperature reading out of range"),
-            Tcn75aError::<R, W>::LimitError { reason: _r, values } => write!(
+            Tcn75aError::OutOfRange => write!(f, "temperature reading out of range"),
+            Tcn75aError::LimitError { reason: _r, values } => write!(
                 f,
                 "limit registers out of range (lo: {}, hi: {})",
                 values.0, values.1
             ),
-            Tcn75aError::<R, W>::RegPtrError(_w) => write!(f, "error writing register pointer"),
-            Tcn75aError::<R, W>::ReadError(_r) => write!(f, "generic read error"),
-            Tcn75aError::<R, W>::WriteError(_w) => write!(f, "generic write error"),
+            Tcn75aError::RegPtrError(_e) => write!(f, "error writing register pointer"),
+            Tcn75aError::ReadError(_e) => write!(f, "generic read error"),
+            Tcn75aError::WriteError(_e) => write!(f, "generic write error"),
+            Tcn75aError::PartialUpdate { written, source: _ } => write!(
+                f,
+                "only the {:?} register was written before the other write failed",
+                written
+            ),
         }
     }
 }
 
-impl<R, W> fmt::Debug for Tcn75aError<R, W>
+impl<E> fmt::Debug for Tcn75aError<E>
 where
-    R: Read,
-    W: Write,
-    <R as Read>::Error: fmt::Debug,
-    <W as Write>::Error: fmt::Debug,
+    E: fmt::Debug,
 {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Tcn75aError::<R, W>::OutOfRange => write!(fmt, "OutOfRange"),
-            Tcn75aError::<R, W>::LimitError { reason, values } => fmt
+            Tcn75aError::OutOfRange => write!(fmt, "OutOfRange"),
+            Tcn75aError::LimitError { reason, values } => fmt
                 .debug_struct("LimitError")
                 .field("reason", reason)
                 .field("values", values)
                 .finish(),
-            Tcn75aError::<R, W>::RegPtrError(w) => fmt.debug_tuple("RegPtrError").field(w).finish(),
-            Tcn75aError::<R, W>::ReadError(r) => fmt.debug_tuple("ReadError").field(r).finish(),
-            Tcn75aError::<R, W>::WriteError(w) => fmt.debug_tuple("WriteError").field(w).finish(),
+            Tcn75aError::RegPtrError(e) => fmt.debug_tuple("RegPtrError").field(e).finish(),
+            Tcn75aError::ReadError(e) => fmt.debug_tuple("ReadError").field(e).finish(),
+            Tcn75aError::WriteError(e) => fmt.debug_tuple("WriteError").field(e).finish(),
+            Tcn75aError::PartialUpdate { written, source } => fmt
+                .debug_struct("PartialUpdate")
+                .field("written", written)
+                .field("source", source)
+                .finish(),
         }
     }
 }
 
 // Mainly for tests.
-impl<R, W> PartialEq<Self> for Tcn75aError<R, W>
+impl<E> PartialEq<Self> for Tcn75aError<E>
 where
-    R: Read,
-    W: Write,
-    <R as Read>::Error: PartialEq<<R as Read>::Error>,
-    <W as Write>::Error: PartialEq<<W as Write>::Error>,
+    E: PartialEq<E>,
 {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Tcn75aError::<R, W>::OutOfRange, Tcn75aError::<R, W>::OutOfRange) => true,
+            (Tcn75aError::OutOfRange, Tcn75aError::OutOfRange) => true,
             (
-                Tcn75aError::<R, W>::LimitError {
+                Tcn75aError::LimitError {
                     reason: sr,
                     values: sv,
                 },
-                Tcn75aError::<R, W>::LimitError {
+                Tcn75aError::LimitError {
                     reason: or,
                     values: ov,
                 },
             ) => sr == or && sv == ov,
-            (Tcn75aError::<R, W>::RegPtrError(s), Tcn75aError::<R, W>::RegPtrError(o)) => s == o,
-            (Tcn75aError::<R, W>::ReadError(s), Tcn75aError::<R, W>::ReadError(o)) => s == o,
-            (Tcn75aError::<R, W>::WriteError(s), Tcn75aError::<R, W>::WriteError(o)) => s == o,
+            (Tcn75aError::RegPtrError(s), Tcn75aError::RegPtrError(o)) => s == o,
+            (Tcn75aError::ReadError(s), Tcn75aError::ReadError(o)) => s == o,
+            (Tcn75aError::WriteError(s), Tcn75aError::WriteError(o)) => s == o,
+            (
+                Tcn75aError::PartialUpdate {
+                    written: sw,
+                    source: ss,
+                },
+                Tcn75aError::PartialUpdate {
+                    written: ow,
+                    source: os,
+                },
+            ) => sw == ow && ss == os,
             _ => false,
         }
     }
 }
 
-impl<R, W> Eq for Tcn75aError<R, W>
-where
-    R: Read,
-    W: Write,
-    Tcn75aError<R, W>: PartialEq<Self>,
-{
-}
+impl<E> Eq for Tcn75aError<E> where Tcn75aError<E>: PartialEq<Self> {}
 
-impl<R, W> Clone for Tcn75aError<R, W>
+impl<E> Clone for Tcn75aError<E>
 where
-    R: Read,
-    W: Write,
-    <R as Read>::Error: Clone,
-    <W as Write>::Error: Clone,
+    E: Clone,
 {
     fn clone(&self) -> Self {
         match self {
-            Tcn75aError::<R, W>::OutOfRange => Tcn75aError::<R, W>::OutOfRange,
-            Tcn75aError::<R, W>::LimitError { reason, values } => Tcn75aError::<R, W>::LimitError {
+            Tcn75aError::OutOfRange => Tcn75aError::OutOfRange,
+            Tcn75aError::LimitError { reason, values } => Tcn75aError::LimitError {
                 reason: *reason,
                 values: *values,
             },
-            Tcn75aError::<R, W>::RegPtrError(w) => Tcn75aError::<R, W>::RegPtrError(w.clone()),
-            Tcn75aError::<R, W>::ReadError(r) => Tcn75aError::<R, W>::ReadError(r.clone()),
-            Tcn75aError::<R, W>::WriteError(w) => Tcn75aError::<R, W>::WriteError(w.clone()),
+            Tcn75aError::RegPtrError(e) => Tcn75aError::RegPtrError(e.clone()),
+            Tcn75aError::ReadError(e) => Tcn75aError::ReadError(e.clone()),
+            Tcn75aError::WriteError(e) => Tcn75aError::WriteError(e.clone()),
+            Tcn75aError::PartialUpdate { written, source } => Tcn75aError::PartialUpdate {
+                written: *written,
+                source: source.clone(),
+            },
         }
     }
 }
 
-impl<R, W> Copy for Tcn75aError<R, W>
-where
-    R: Read,
-    W: Write,
-    <R as Read>::Error: Copy,
-    <W as Write>::Error: Copy,
-{
-}
+impl<E> Copy for Tcn75aError<E> where E: Copy {}
 
-/** Convenience type for representing [`Tcn75aError`]s where `T` implements both [`Read`]
-and [`Write`].
+/** Convenience type for representing [`Tcn75aError`]s where `T` implements [`I2c`].
 
 [`Tcn75aError`]: ./enum.Tcn75aError.html
-[`Read`]: ../embedded_hal/blocking/i2c/trait.Read.html
-[`Write`]: ../embedded_hal/blocking/i2c/trait.Write.html
+[`I2c`]: ../embedded_hal/i2c/trait.I2c.html
 */
-pub type Error<T> = Tcn75aError<T, T>;
+pub type Error<T> = Tcn75aError<<T as ErrorType>::Error>;
 
 impl<T> Tcn75a<T>
 where
-    T: Read + Write,
+    T: I2c,
 {
     /** Initializes all the data required to read and write a TCN75A on an I2C bus.
 
@@ -249,7 +421,7 @@ where
 
     # Arguments
 
-    * `ctx`: A type `T` implementing the [I2C traits] of [`embedded_hal`].
+    * `ctx`: A type `T` implementing the [`I2c`] trait of [`embedded_hal`].
     * `address`: I2C address of the TCN75A sensor.
 
     # Examples
@@ -273,7 +445,7 @@ where
     # }
     ```
 
-    [I2C traits]: ../embedded_hal/blocking/i2c/index.html#traits
+    [`I2c`]: ../embedded_hal/i2c/trait.I2c.html
     [`embedded_hal`]: ../embedded_hal
     */
     pub fn new(ctx: T, address: u8) -> Self {
@@ -302,10 +474,9 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
     # use fixed::types::I8F8;
-    # use tcn75a::{Tcn75a, Tcn75aError};
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # use tcn75a::{Tcn75a, Error};
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     // All subsequent examples should assume tcn is a `Tcn75a`
@@ -350,13 +521,44 @@ where
         }
 
         self.ctx
-            .write(self.address, &ptr.to_le_bytes())
+            .write(self.address, &[ptr])
             .map(|_| {
                 self.reg = Some(ptr);
             })
             .map_err(|e| {
                 self.reg = None;
-                Tcn75aError::RegPtrError(e)
+                Tcn75aError::RegPtrError(e.into())
+            })
+    }
+
+    /** Sets the register pointer to `ptr` and reads `buf` from it as a single atomic
+    [`I2c::write_read`] transaction if the register pointer cache doesn't already point to `ptr`,
+    or a lone [`I2c::read`] otherwise. Either way, another controller on the bus can't observe or
+    move the pointer in between the set and the read.
+
+    On success, the register pointer cache is left pointing at `ptr`. On failure, the register
+    pointer cache is flushed, since a failed [`I2c::write_read`] leaves the pointer's true value
+    unknown.
+
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    [`I2c::read`]: ../embedded_hal/i2c/trait.I2c.html#method.read
+    */
+    fn read_reg(&mut self, ptr: u8, buf: &mut [u8]) -> Result<(), Error<T>> {
+        if self.reg == Some(ptr) {
+            return self
+                .ctx
+                .read(self.address, buf)
+                .map_err(|e| Tcn75aError::ReadError(e.into()));
+        }
+
+        self.ctx
+            .write_read(self.address, &[ptr], buf)
+            .map(|_| {
+                self.reg = Some(ptr);
+            })
+            .map_err(|e| {
+                self.reg = None;
+                Tcn75aError::ReadError(e.into())
             })
     }
 
@@ -366,15 +568,18 @@ where
 
     /** Gets a raw (9-12 bit) temperature reading from the TCN75A.
 
-    Returns the temperature using:
-
-    * An I2C write transaction to set the register pointer (if necessary), and
-    * An I2C read transaction to read the Ambient Temperature Register.
+    If the register pointer cache doesn't already point at the Ambient Temperature Register, this
+    sets the pointer and reads the register in a single atomic [`I2c::write_read`] transaction
+    (a repeated-START), so another controller on the bus can't move the pointer in between;
+    otherwise, it's a lone [`I2c::read`].
 
-    For any `Ok` or `Err` return variant besides [`Tcn75aError::RegPtrError`], the register
-    pointer cache will point to register 0 after this function returns. The sensor config
+    If the config cache is already filled, an `Ok` return value means the register pointer cache
+    points to register 0, and the sensor config
     cache is untouched.
 
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    [`I2c::read`]: ../embedded_hal/i2c/trait.I2c.html#method.read
+
     # Internals
 
     Currently the [`temperature`] function does not use the [`Resolution`] data in the config
@@ -422,10 +627,9 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
     # use fixed::types::I8F8;
-    # use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, Resolution};
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # use tcn75a::{Tcn75a, Error, ConfigReg, Resolution};
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     // Assume `tcn` and the controller were _just_ powered on.
@@ -441,23 +645,26 @@ where
     # }
     ```
 
-    # Errors
+    If the config cache is empty, this function also issues a Sensor Configuration Register read
+    (as [`config_reg`] would) to learn the real [`Resolution`] before validating the reading; in
+    that case the register pointer cache is left pointing at register 1 (the Sensor Configuration
+    Register) rather than register 0. If that supplementary read fails, validation falls back to
+    the most conservative resolution, [`Resolution::Bits9`], rather than failing [`temperature`]
+    itself.
 
-    * [`Tcn75aError::RegPtrError`]: Returned if the I2C write to set the register pointer failed.
-      The register pointer cache is flushed.
-    * [`Tcn75aError::ReadError`]: Returned if the I2C read to get the temperature register
-      contents failed.
-    * [`Tcn75aError::OutOfRange`]: The I2C read succeeded, but some bits which _must_ be 0
-      _regardless_ of resolution were 1.
+    # Errors
 
-      Currently an [`OutOfRange`][`Tcn75aError::OutOfRange`] error is conservative, because
-      [`temperature`] does not use cached [`Resolution`] data; it will not detect e.g. "bits set
-      that indicate a 12-bit value, but the [`Resolution`] is [`Resolution::Bits9`]".
+    * [`Tcn75aError::ReadError`]: Returned if the I2C transaction to set the register pointer
+      and/or read the temperature register contents failed. The register pointer cache is
+      flushed.
+    * [`Tcn75aError::OutOfRange`]: The I2C read succeeded, but some bits which _must_ be 0 at the
+      effective [`Resolution`] were 1 (see [`Resolution::out_of_range_mask`]).
 
-    [`Tcn75aError::RegPtrError`]: ./enum.Tcn75aError.html#variant.RegPtrError
     [`Q` format]: https://en.wikipedia.org/wiki/Q_(number_format)
     [`temperature`]: ./struct.Tcn75a.html#method.temperature
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
     [`Resolution`]: ./enum.Resolution.html
+    [`Resolution::out_of_range_mask`]: ./enum.Resolution.html
     [`Tcn75aError::ReadError`]: ./enum.Tcn75aError.html#variant.ReadError
     [`Tcn75aError::OutOfRange`]: ./enum.Tcn75aError.html#variant.OutOfRange
     [`Resolution::Bits9`]: ./enum.Resolution.html#variant.Bits9
@@ -465,18 +672,20 @@ where
     pub fn temperature(&mut self) -> Result<Temperature, Error<T>> {
         let mut temp: [u8; 2] = [0u8; 2];
 
-        self.set_reg_ptr(0x00)?;
-        self.ctx
-            .read(self.address, &mut temp)
-            .map_err(Tcn75aError::ReadError)?;
+        self.read_reg(0x00, &mut temp)?;
 
         let raw_temp = i16::from_be_bytes(temp);
 
-        // TODO: Vary the number of its checked based on Resolution and cache
-        // contents. Fall back to most conservative (9Bits) if unknown
-        // Resolution.
-        if (raw_temp & 0x000f) == 0 {
-            Ok(Temperature(I8F8::from_bits(raw_temp)))
+        let resolution = match self.cfg {
+            Some(cfg) => cfg.get_resolution(),
+            None => self
+                .config_reg()
+                .map(|cfg| cfg.get_resolution())
+                .unwrap_or(Resolution::Bits9),
+        };
+
+        if (raw_temp & resolution.out_of_range_mask()) == 0 {
+            Ok(Temperature(I8F8::from_bits(raw_temp), resolution))
         } else {
             Err(Tcn75aError::OutOfRange)
         }
@@ -484,14 +693,16 @@ where
 
     /** Gets the current configuration of the TCN75A.
 
-    The contents of the Sensor Configuration Register are returned using:
-
-    * An I2C write transaction to set the register pointer (if necessary), and
-    * An I2C read transaction to read the Sensor Configuration Register (if necessary).
+    If the config cache is empty, the contents of the Sensor Configuration Register are read- as a
+    single atomic [`I2c::write_read`] transaction if the register pointer cache doesn't already
+    point at it, or a lone [`I2c::read`] otherwise.
 
     The contents of the Sensor Configuration Register are cached; no I2C transaction occurs
     if the config cache contains a previously-read value.
 
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    [`I2c::read`]: ../embedded_hal/i2c/trait.I2c.html#method.read
+
     For an `Ok` variant return value, the cache behavior varies:
 
     * If the config cache is valid, neither the register pointer or the sensor config cache
@@ -508,9 +719,8 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
-    # use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, Resolution, FaultQueue};
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # use tcn75a::{Tcn75a, Error, ConfigReg, Resolution, FaultQueue};
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     let mut cfg = tcn.config_reg()?; // Let's change some settings!
@@ -530,15 +740,12 @@ where
 
     # Errors
 
-    * [`Tcn75aError::RegPtrError`]: Returned if the I2C write to set the register pointer failed.
-      The register pointer cache is flushed. The config register cache is untouched.
-    * [`Tcn75aError::ReadError`]: Returned if the I2C read to get the config register
-      contents failed. The register pointer cache is set to register 1. The config register
-      cache is flushed.
+    * [`Tcn75aError::ReadError`]: Returned if the I2C transaction to set the register pointer
+      and/or read the config register contents failed. The register pointer cache is flushed.
+      The config register cache is untouched.
 
     [`ConfigReg`]: ./struct.ConfigReg.html
     [`Errors`]: ./struct.Tcn75a.html#errors-2
-    [`Tcn75aError::RegPtrError`]: ./enum.Tcn75aError.html#variant.RegPtrError
     [`Tcn75aError::ReadError`]: ./enum.Tcn75aError.html#variant.ReadError
     */
     pub fn config_reg(&mut self) -> Result<ConfigReg, Error<T>> {
@@ -548,24 +755,11 @@ where
             return Ok(curr);
         }
 
-        self.set_reg_ptr(0x01)?;
-        let cfg = self
-            .ctx
-            .read(self.address, &mut buf)
-            .map(|_| {
-                let cfg = ConfigReg::from_bytes(buf);
-
-                self.cfg = Some(cfg);
-                cfg
-            })
-            .map_err(|e| {
-                self.cfg = None;
-                Tcn75aError::ReadError(e)
-            })?;
+        self.read_reg(0x01, &mut buf)?;
+        let cfg = ConfigReg::from_bytes(buf);
+        self.cfg = Some(cfg);
 
         Ok(cfg)
-        // Ok(buf.try_into().unwrap())
-        // Ok(&*buf.try_into().unwrap())
     }
 
     /** Sets the current configuration of the TCN75A.
@@ -586,12 +780,11 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
-    # use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, CompInt, Limits};
+    # use tcn75a::{Tcn75a, Error, ConfigReg, CompInt, Limits};
     # use fixed::types::I8F8;
     # use fixed_macro::fixed;
     # use std::convert::TryInto;
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     let mut cfg = ConfigReg::new();
@@ -633,7 +826,7 @@ where
             .map_err(|e| {
                 self.reg = None;
                 self.cfg = None;
-                Tcn75aError::WriteError(e)
+                Tcn75aError::WriteError(e.into())
             })?;
         self.reg = Some(0x01);
 
@@ -642,10 +835,11 @@ where
 
     /** Retrieves the lower and upper temperature limits before the TCN75A asserts an alarm.
 
-    The contents of the Hysteresis and Limit-Set Registers are returned using _two_ of:
-
-    * An I2C write transaction to set the register pointer (if necessary), and
-    * An I2C read transaction to read each register (always occurs).
+    Each register is read individually- as a single atomic [`I2c::write_read`] transaction if the
+    register pointer cache doesn't already point at it, or a lone [`I2c::read`] otherwise. The two
+    registers still require two I2C transactions in total, so another controller could still
+    observe/move the pointer _between_ them; only the pointer-set-then-read for each individual
+    register is atomic.
 
     For an `Ok` variant return value, the register pointer cache points to register 3. For
     an `Err` variant return value, the register pointer cache's value _should not be relied
@@ -657,10 +851,9 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
-    # use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, AlertPolarity, Limits};
+    # use tcn75a::{Tcn75a, Tcn75aError, Error, ConfigReg, AlertPolarity, Limits};
     # use std::convert::TryInto;
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     let mut cfg = ConfigReg::new();
@@ -699,15 +892,12 @@ where
 
     # Errors
 
-    * [`Tcn75aError::RegPtrError`]: Returned if the I2C write to set the register pointer for
-      _either_ of the above registers failed. The register pointer cache is flushed.
-    * [`Tcn75aError::ReadError`]: Returned if the I2C read to get _either_ of the above register
-      contents failed. The register pointer cache is set to register is either 2 or 3.
+    * [`Tcn75aError::ReadError`]: Returned if the I2C transaction to set the register pointer
+      and/or read _either_ of the above registers failed. The register pointer cache is flushed.
     * [`Tcn75aError::LimitError`]: Both registers were read successfully, but violated invariants
       assumed by this library. The error reason and the values read are returned, as described
       [above]. The register pointer cache is set to 3.
 
-    [`Tcn75aError::RegPtrError`]: ./enum.Tcn75aError.html#variant.RegPtrError
     [`Tcn75aError::ReadError`]: ./enum.Tcn75aError.html#variant.ReadError
     [`Tcn75aError::LimitError`]: ./enum.Tcn75aError.html#variant.LimitError
     [above]: ./enum.Tcn75aError.html#variant.LimitError
@@ -716,19 +906,11 @@ where
         let mut buf: [u8; 2] = [0u8; 2];
         let mut lim: (I8F8, I8F8) = (0.into(), 0.into());
 
-        self.set_reg_ptr(0x02)?;
-        lim.0 = self
-            .ctx
-            .read(self.address, &mut buf)
-            .map(|_| I8F8::from_be_bytes(buf))
-            .map_err(Tcn75aError::ReadError)?;
+        self.read_reg(0x02, &mut buf)?;
+        lim.0 = I8F8::from_be_bytes(buf);
 
-        self.set_reg_ptr(0x03)?;
-        lim.1 = self
-            .ctx
-            .read(self.address, &mut buf)
-            .map(|_| I8F8::from_be_bytes(buf))
-            .map_err(Tcn75aError::ReadError)?;
+        self.read_reg(0x03, &mut buf)?;
+        lim.1 = I8F8::from_be_bytes(buf);
 
         TryFrom::try_from(lim).map_err(|r| Tcn75aError::LimitError {
             reason: r,
@@ -750,9 +932,10 @@ where
     value.
 
     Although the TCN75A can tolerate a Hysteresis Register value which exceeds the Limit-Set
-    Register value, for simplicity, this crate attempts to [disallow] it. _At present, a failed
-    write to the Limit-Set Register via `set_limits` may result in a Hysteresis Register value
-    which exceeds the Limit-Set Register value_.
+    Register value, for simplicity, this crate attempts to [disallow] it. If the Limit-Set write
+    fails after the Hysteresis write already succeeded, `set_limits` attempts a rollback write to
+    restore the invariant rather than leaving the two registers in a torn state; see
+    [`Tcn75aError::PartialUpdate`] below for what happens if that rollback itself fails.
 
     # Examples
 
@@ -766,12 +949,11 @@ where
     # cfg_if::cfg_if! {
     # if #[cfg(any(target_os = "linux", target_os = "android"))] {
     # use linux_embedded_hal::I2cdev;
-    # use embedded_hal::blocking::i2c::{Read, Write};
-    # use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, AlertPolarity, Limits};
+    # use tcn75a::{Tcn75a, Error, ConfigReg, AlertPolarity, Limits};
     # use std::convert::TryInto;
     # use fixed::types::I8F8;
     # use fixed_macro::fixed;
-    # fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+    # fn main() -> Result<(), Error<I2cdev>> {
     # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
     # let mut tcn = Tcn75a::new(i2c, 0x48);
     let mut cfg = ConfigReg::new();
@@ -793,13 +975,20 @@ where
 
     # Errors
 
-    * [`Tcn75aError::WriteError`]: Returned if the I2C write to set _either_ the Hysteresis or
-      Limit-Set register failed. The register pointer cache is flushed.
+    * [`Tcn75aError::WriteError`]: Returned if the I2C write to set the Hysteresis register
+      failed. The register pointer cache is flushed.
+    * [`Tcn75aError::PartialUpdate`]: Returned if the Hysteresis register was written
+      successfully but the subsequent write to the Limit-Set register failed. A rollback write
+      forcing the Limit-Set register to `127.5`, the largest [`Q8.1`] value, is attempted before
+      returning, to restore the Hysteresis-less-than-Limit-Set invariant; the register pointer
+      cache is flushed regardless of whether the rollback succeeds.
 
     [disallow]: ./struct.Limits.html
     [`Limits`]: ./struct.Limits.html
     [polarity]: ./enum.AlertPolarity.html
+    [`Q8.1`]: https://en.wikipedia.org/wiki/Q_(number_format)
     [`Tcn75aError::WriteError`]: ./enum.Tcn75aError.html#variant.WriteError
+    [`Tcn75aError::PartialUpdate`]: ./enum.Tcn75aError.html#variant.PartialUpdate
     */
     pub fn set_limits(&mut self, limits: Limits) -> Result<(), Error<T>> {
         let mut buf: [u8; 3] = [0u8; 3];
@@ -810,24 +999,196 @@ where
         buf[1..3].copy_from_slice(&lower.to_be_bytes());
 
         self.ctx.write(self.address, &buf).map_err(|e| {
-            // TODO: PartialUpdate variant?
             self.reg = None;
-            Tcn75aError::WriteError(e)
+            Tcn75aError::WriteError(e.into())
         })?;
         self.reg = Some(0x02); // Needed?
 
         // Reg ptr
         buf[0] = 0x03;
         buf[1..3].copy_from_slice(&upper.to_be_bytes());
-        self.ctx.write(self.address, &buf).map_err(|e| {
+        if let Err(e) = self.ctx.write(self.address, &buf) {
+            // Only the Hysteresis register is known-committed at this point, which may leave the
+            // device with Hysteresis > Limit-Set. Force the Limit-Set register to the largest
+            // valid Q8.1 value- always >= any valid Hysteresis value- so the pair is at least
+            // consistent again, even though it's no longer the pair the caller asked for. The
+            // rollback write's own success or failure isn't reported; either way the register
+            // pointer cache can't be trusted, since we don't know which half of the failed write
+            // actually reached the device.
+            let max_limit = I8F8::from_num(127) + I8F8::from_num(1) / 2;
+            buf[1..3].copy_from_slice(&max_limit.to_be_bytes());
+            let _ = self.ctx.write(self.address, &buf);
             self.reg = None;
-            Tcn75aError::WriteError(e)
-        })?;
+            return Err(Tcn75aError::PartialUpdate {
+                written: RegId::Hysteresis,
+                source: e,
+            });
+        }
         self.reg = Some(0x03);
 
         Ok(())
     }
 
+    /** Puts the TCN75A into shutdown (micro-amp idle) mode by setting the SHUTDOWN bit.
+
+    In shutdown, the ADC stops converting and the device draws its lowest idle current; the
+    Hysteresis, Limit-Set, and Ambient Temperature Registers keep their last values and remain
+    readable.
+
+    This reads the current [`ConfigReg`] (via [`config_reg`], an I2C read only if the cache is
+    empty) and writes it back (via [`set_config_reg`]) with SHUTDOWN set, leaving every other
+    field untouched. The config cache reflects the new value on success.
+
+    # Examples
+
+    ```
+    # cfg_if::cfg_if! {
+    # if #[cfg(any(target_os = "linux", target_os = "android"))] {
+    # use linux_embedded_hal::I2cdev;
+    # use tcn75a::{Tcn75a, Error};
+    # fn main() -> Result<(), Error<I2cdev>> {
+    # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
+    # let mut tcn = Tcn75a::new(i2c, 0x48);
+    // Done polling for a while- park the TCN75A at its lowest idle current.
+    tcn.shutdown()?;
+    # Ok(())
+    # }
+    # } else {
+    # fn main() {
+    # }
+    # }
+    # }
+    ```
+
+    # Errors
+
+    Same as [`config_reg`] and [`set_config_reg`].
+
+    [`ConfigReg`]: ./struct.ConfigReg.html
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
+    [`set_config_reg`]: ./struct.Tcn75a.html#method.set_config_reg
+    */
+    pub fn shutdown(&mut self) -> Result<(), Error<T>> {
+        let mut cfg = self.config_reg()?;
+        cfg.set_shutdown(Shutdown::Enable);
+        self.set_config_reg(cfg)
+    }
+
+    /** Wakes the TCN75A from shutdown by clearing the SHUTDOWN bit, resuming free-running
+    conversions.
+
+    See [`shutdown`] for the register access pattern and cache behavior; this clears SHUTDOWN
+    instead of setting it.
+
+    # Examples
+
+    ```
+    # cfg_if::cfg_if! {
+    # if #[cfg(any(target_os = "linux", target_os = "android"))] {
+    # use linux_embedded_hal::I2cdev;
+    # use tcn75a::{Tcn75a, Error};
+    # fn main() -> Result<(), Error<I2cdev>> {
+    # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
+    # let mut tcn = Tcn75a::new(i2c, 0x48);
+    tcn.shutdown()?;
+    // ... Later.
+    tcn.wake()?;
+    println!("Temperature is: {}", f32::from(fixed::types::I8F8::from(tcn.temperature()?)));
+    # Ok(())
+    # }
+    # } else {
+    # fn main() {
+    # }
+    # }
+    # }
+    ```
+
+    # Errors
+
+    Same as [`config_reg`] and [`set_config_reg`].
+
+    [`shutdown`]: ./struct.Tcn75a.html#method.shutdown
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
+    [`set_config_reg`]: ./struct.Tcn75a.html#method.set_config_reg
+    */
+    pub fn wake(&mut self) -> Result<(), Error<T>> {
+        let mut cfg = self.config_reg()?;
+        cfg.set_shutdown(Shutdown::Disable);
+        self.set_config_reg(cfg)
+    }
+
+    /** Takes a single reading while otherwise leaving the TCN75A in shutdown, for battery-powered
+    designs that want periodic samples at microamp idle current instead of continuously running
+    the ADC.
+
+    Sets SHUTDOWN and ONE-SHOT, which triggers exactly one conversion; the TCN75A clears ONE-SHOT
+    and re-enters shutdown on its own once the conversion completes. This function waits out the
+    conversion time appropriate to the current [`Resolution`] (~30/75/150/300ms for
+    9/10/11/12-bit) using `delay`, then reads back and returns the [`Temperature`].
+
+    The config cache is updated to reflect both the bits written and the ONE-SHOT bit's
+    self-clear, without an extra I2C transaction to confirm it.
+
+    # Examples
+
+    ```
+    # cfg_if::cfg_if! {
+    # if #[cfg(any(target_os = "linux", target_os = "android"))] {
+    # use linux_embedded_hal::I2cdev;
+    # use linux_embedded_hal::Delay;
+    # use tcn75a::{Tcn75a, Error};
+    # fn main() -> Result<(), Error<I2cdev>> {
+    # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
+    # let mut tcn = Tcn75a::new(i2c, 0x48);
+    let mut delay = Delay {};
+    loop {
+        let temp = tcn.one_shot(&mut delay)?;
+        println!("Temperature is: {}", f32::from(fixed::types::I8F8::from(temp)));
+        // ... Sleep for the rest of the sampling period at microamp idle current.
+    }
+    # }
+    # } else {
+    # fn main() {
+    # }
+    # }
+    # }
+    ```
+
+    # Errors
+
+    Same as [`config_reg`], [`set_config_reg`], and [`temperature`].
+
+    [`Resolution`]: ./enum.Resolution.html
+    [`Temperature`]: ./struct.Temperature.html
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
+    [`set_config_reg`]: ./struct.Tcn75a.html#method.set_config_reg
+    [`temperature`]: ./struct.Tcn75a.html#method.temperature
+    */
+    pub fn one_shot<D>(&mut self, delay: &mut D) -> Result<Temperature, Error<T>>
+    where
+        D: DelayNs,
+    {
+        let mut cfg = self.config_reg()?;
+        cfg.set_shutdown(Shutdown::Enable);
+        cfg.set_one_shot(OneShot::Enabled);
+        self.set_config_reg(cfg)?;
+
+        delay.delay_ms(match cfg.get_resolution() {
+            Resolution::Bits9 => 30,
+            Resolution::Bits10 => 75,
+            Resolution::Bits11 => 150,
+            Resolution::Bits12 => 300,
+        });
+
+        // The TCN75A clears ONE-SHOT on its own once the conversion completes; reflect that in
+        // the cache instead of spending a read to confirm it.
+        if let Some(cached) = self.cfg.as_mut() {
+            cached.set_one_shot(OneShot::Disabled);
+        }
+
+        self.temperature()
+    }
+
     /** Release the resources used to perform TCN75A transactions.
 
     No I2C transactions occur in this function. The wrapped [`embedded_hal`] instance is
@@ -865,22 +1226,164 @@ where
     pub fn free(self) -> T {
         self.ctx
     }
+
+    /** Reads the Sensor Configuration, Hysteresis, and Limit-Set registers (via [`config_reg`]
+    and [`limits`], so cached values are reused where available) and bundles them into a
+    [`Tcn75aState`] snapshot.
+
+    Pair with [`restore`] to checkpoint a sensor's full configuration- to non-volatile storage,
+    for instance- and deterministically re-establish it later, rather than rebuilding each
+    register by hand.
+
+    # Examples
+
+    ```
+    # cfg_if::cfg_if! {
+    # if #[cfg(any(target_os = "linux", target_os = "android"))] {
+    # use linux_embedded_hal::I2cdev;
+    # use tcn75a::{Tcn75a, Error};
+    # fn main() -> Result<(), Error<I2cdev>> {
+    # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
+    # let mut tcn = Tcn75a::new(i2c, 0x48);
+    let state = tcn.snapshot()?;
+    // ... Stash `state` somewhere durable.
+    # Ok(())
+    # }
+    # } else {
+    # fn main() {
+    # }
+    # }
+    # }
+    ```
+
+    # Errors
+
+    Same as [`config_reg`] and [`limits`].
+
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
+    [`limits`]: ./struct.Tcn75a.html#method.limits
+    [`restore`]: ./struct.Tcn75a.html#method.restore
+    [`Tcn75aState`]: ./struct.Tcn75aState.html
+    */
+    pub fn snapshot(&mut self) -> Result<Tcn75aState, Error<T>> {
+        let cfg = self.config_reg()?;
+        let limits = self.limits()?;
+
+        Ok(Tcn75aState { cfg, limits })
+    }
+
+    /** Reprograms the Sensor Configuration, Hysteresis, and Limit-Set registers from a
+    [`Tcn75aState`] snapshot (via [`set_config_reg`] and [`set_limits`]), repopulating the `cfg`
+    and register pointer caches as a side effect of each.
+
+    # Errors
+
+    Same as [`set_config_reg`] and [`set_limits`]. If [`set_config_reg`] fails, [`set_limits`] is
+    not attempted, and the device's Hysteresis/Limit-Set registers are left whatever they were
+    before this call.
+
+    [`set_config_reg`]: ./struct.Tcn75a.html#method.set_config_reg
+    [`set_limits`]: ./struct.Tcn75a.html#method.set_limits
+    [`Tcn75aState`]: ./struct.Tcn75aState.html
+    */
+    pub fn restore(&mut self, state: &Tcn75aState) -> Result<(), Error<T>> {
+        self.set_config_reg(state.cfg)?;
+        self.set_limits(state.limits)?;
+
+        Ok(())
+    }
+
+    /** Like [`temperature`], but issues the register-pointer write and the Ambient Temperature
+    Register read as a single atomic [`I2c::write_read`] transaction on every call, and never
+    touches the `reg`/`cfg` caches. Safe to call on I2C buses shared with other controllers.
+
+    Since the `cfg` cache is never consulted (doing so would mean trusting a value that could be
+    stale on a shared bus), the out-of-range check always uses the most permissive mask
+    ([`Resolution::Bits12`]'s), and the returned [`Temperature`] reports [`Resolution::Bits12`]
+    regardless of the device's actual resolution. Use [`config_reg_uncached`] first if you need
+    the real resolution reflected in the result.
+
+    [`temperature`]: ./struct.Tcn75a.html#method.temperature
+    [`config_reg_uncached`]: ./struct.Tcn75a.html#method.config_reg_uncached
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    [`Resolution::Bits12`]: ./enum.Resolution.html#variant.Bits12
+    */
+    pub fn temperature_uncached(&mut self) -> Result<Temperature, Error<T>> {
+        let mut temp: [u8; 2] = [0u8; 2];
+
+        self.ctx
+            .write_read(self.address, &[0x00], &mut temp)
+            .map_err(|e| Tcn75aError::ReadError(e.into()))?;
+
+        let raw_temp = i16::from_be_bytes(temp);
+
+        if (raw_temp & Resolution::Bits12.out_of_range_mask()) == 0 {
+            Ok(Temperature(I8F8::from_bits(raw_temp), Resolution::Bits12))
+        } else {
+            Err(Tcn75aError::OutOfRange)
+        }
+    }
+
+    /** Like [`config_reg`], but issues the register-pointer write and the Sensor Configuration
+    Register read as a single atomic [`I2c::write_read`] transaction. Never reads from or writes
+    to the `cfg` cache, so every call performs an I2C transaction.
+
+    [`config_reg`]: ./struct.Tcn75a.html#method.config_reg
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    */
+    pub fn config_reg_uncached(&mut self) -> Result<ConfigReg, Error<T>> {
+        let mut buf: [u8; 1] = [0u8; 1];
+
+        self.ctx
+            .write_read(self.address, &[0x01], &mut buf)
+            .map(|_| ConfigReg::from_bytes(buf))
+            .map_err(|e| Tcn75aError::ReadError(e.into()))
+    }
+
+    /** Like [`limits`], but each of the Hysteresis and Limit-Set Registers is read via its own
+    atomic [`I2c::write_read`] transaction, rather than a separate pointer write followed by a
+    read. The two registers still require two I2C transactions in total (they're adjacent but
+    distinct), so another controller could still observe/move the pointer _between_ them; this
+    only closes the race within each individual register read.
+
+    [`limits`]: ./struct.Tcn75a.html#method.limits
+    [`I2c::write_read`]: ../embedded_hal/i2c/trait.I2c.html#method.write_read
+    */
+    pub fn limits_uncached(&mut self) -> Result<Limits, Error<T>> {
+        let mut buf: [u8; 2] = [0u8; 2];
+        let mut lim: (I8F8, I8F8) = (0.into(), 0.into());
+
+        lim.0 = self
+            .ctx
+            .write_read(self.address, &[0x02], &mut buf)
+            .map(|_| I8F8::from_be_bytes(buf))
+            .map_err(|e| Tcn75aError::ReadError(e.into()))?;
+
+        lim.1 = self
+            .ctx
+            .write_read(self.address, &[0x03], &mut buf)
+            .map(|_| I8F8::from_be_bytes(buf))
+            .map_err(|e| Tcn75aError::ReadError(e.into()))?;
+
+        TryFrom::try_from(lim).map_err(|r| Tcn75aError::LimitError {
+            reason: r,
+            values: lim,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     extern crate std;
     use std::convert::TryInto;
-    use std::io::ErrorKind;
     use std::vec;
 
     use super::{
-        AlertPolarity, ConfigReg, LimitError, OneShot, Resolution, Shutdown, Tcn75a, Tcn75aError,
-    };
-    use embedded_hal_mock::{
-        i2c::{Mock as I2cMock, Transaction as I2cTransaction},
-        MockError,
+        AlertPolarity, ConfigReg, LimitError, Limits, OneShot, RegId, Resolution, Shutdown, Tcn75a,
+        Tcn75aError, Tcn75aState,
     };
+    use embedded_hal::i2c::ErrorKind;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
     use fixed::types::I8F8;
     use fixed_macro::fixed;
 
@@ -932,7 +1435,7 @@ mod tests {
         let mut tcn = mk_tcn75a(
             &[
                 I2cTransaction::write(0x48, vec![0]),
-                I2cTransaction::write(0x48, vec![1]).with_error(MockError::Io(ErrorKind::Other)),
+                I2cTransaction::write(0x48, vec![1]).with_error(ErrorKind::Other),
                 I2cTransaction::write(0x48, vec![1]),
             ],
             0x48,
@@ -942,7 +1445,7 @@ mod tests {
         assert_eq!(tcn.reg, Some(0));
         assert_eq!(
             tcn.set_reg_ptr(1),
-            Err(Tcn75aError::RegPtrError(MockError::Io(ErrorKind::Other)))
+            Err(Tcn75aError::RegPtrError(ErrorKind::Other.into()))
         );
         assert_eq!(tcn.reg, None);
         assert_eq!(tcn.set_reg_ptr(1), Ok(()));
@@ -961,14 +1464,17 @@ mod tests {
     fn create_read_free() {
         let mut tcn = mk_tcn75a(
             &[
-                I2cTransaction::write(0x48, vec![0]),
                 // Fake temp data
-                I2cTransaction::read(0x48, vec![0x7f, 0x80]),
-                // Cache initialized.
-                I2cTransaction::read(0x48, vec![0x7f, 0x80]),
+                I2cTransaction::write_read(0x48, vec![0], vec![0x7f, 0x80]),
+                // Cfg cache empty: also reads the Sensor Configuration Register to learn the
+                // effective Resolution (default all-zero config = Bits9).
+                I2cTransaction::write_read(0x48, vec![1], vec![0x00]),
+                // That config read left the register pointer cache at 1, so the next
+                // temperature() call has to move it back to 0 (cfg is cached now, though).
+                I2cTransaction::write_read(0x48, vec![0], vec![0x7f, 0x80]),
                 // Negative value (different addr).
-                I2cTransaction::write(0x49, vec![0]),
-                I2cTransaction::read(0x49, vec![0xff, 0xf0]),
+                I2cTransaction::write_read(0x49, vec![0], vec![0xff, 0x80]),
+                I2cTransaction::write_read(0x49, vec![1], vec![0x00]),
             ],
             0x48,
         );
@@ -997,20 +1503,20 @@ mod tests {
         assert_eq!(tcn.reg, None);
         assert_eq!(tcn.cfg, None);
 
+        // Negative value (-0.5C).
         let temp = tcn.temperature();
         assert!(temp.is_ok());
-        assert_eq!(
-            I8F8::from(temp.unwrap()),
-            I8F8::from_bits(((0 << 4) - 1) << 4)
-        );
+        assert_eq!(I8F8::from(temp.unwrap()), I8F8::from_bits(-128));
     }
 
     #[test]
     fn read_invalid() {
         let mut tcn = mk_tcn75a(
             &[
-                I2cTransaction::write(0x48, vec![0]),
-                I2cTransaction::read(0x48, vec![0x80, 0x01]),
+                I2cTransaction::write_read(0x48, vec![0], vec![0x80, 0x01]),
+                // Cfg cache empty: the effective Resolution (Bits9 here) is still looked up
+                // before the out-of-range check runs.
+                I2cTransaction::write_read(0x48, vec![1], vec![0x00]),
             ],
             0x48,
         );
@@ -1021,18 +1527,52 @@ mod tests {
     }
 
     #[test]
-    fn write_read_config() {
+    fn temperature_uses_cached_resolution() {
+        let (cfg1, _) = mk_cfg_regs();
+        assert_eq!(cfg1.get_resolution(), Resolution::Bits12);
+
         let mut tcn = mk_tcn75a(
             &[
                 I2cTransaction::write(0x48, vec![1, 0b01100000]),
-                I2cTransaction::read(0x48, vec![0b01100000]),
+                // Bits 4-6 set- invalid at Bits9 but valid at Bits12.
+                I2cTransaction::write_read(0x48, vec![0], vec![0x48, 0x70]),
+            ],
+            0x48,
+        );
+
+        assert_eq!(tcn.set_config_reg(cfg1), Ok(()));
+
+        let temp = tcn.temperature();
+        assert!(temp.is_ok());
+        let temp = temp.unwrap();
+        assert_eq!(temp.resolution(), Resolution::Bits12);
+        assert_eq!(I8F8::from(temp), I8F8::from_bits(0x4870));
+    }
+
+    #[test]
+    fn temperature_falls_back_to_bits9_on_config_read_failure() {
+        let mut tcn = mk_tcn75a(
+            &[
+                // Bits 4-6 set- invalid at the Bits9 fallback.
+                I2cTransaction::write_read(0x48, vec![0], vec![0x48, 0x70]),
+                I2cTransaction::write_read(0x48, vec![1], vec![0x00]).with_error(ErrorKind::Other),
             ],
             0x48,
         );
 
+        let temp = tcn.temperature();
+        assert!(temp.is_err());
+        assert_eq!(temp.unwrap_err(), Tcn75aError::OutOfRange);
+    }
+
+    #[test]
+    fn write_read_config() {
+        let mut tcn = mk_tcn75a(&[I2cTransaction::write(0x48, vec![1, 0b01100000])], 0x48);
+
         let (cfg1, _) = mk_cfg_regs();
 
-        // Set the config register and read it back.
+        // Set the config register and read it back- set_config_reg() already primes the cache,
+        // so the read-back is free (no further I2C transaction).
         assert_eq!(tcn.cfg, None);
         assert_eq!(tcn.set_config_reg(cfg1), Ok(()));
         assert_eq!(tcn.cfg, Some(cfg1));
@@ -1047,11 +1587,6 @@ mod tests {
                 I2cTransaction::write(0x48, vec![1, 0b01100000]),
                 // Fake reg set.
                 I2cTransaction::write(0x48, vec![0]),
-                // Cached value doesn't match.
-                I2cTransaction::write(0x48, vec![1]),
-                I2cTransaction::read(0x48, vec![0b01100000]),
-                // Cache value matches.
-                I2cTransaction::read(0x48, vec![0b01100000]),
             ],
             0x48,
         );
@@ -1060,7 +1595,8 @@ mod tests {
         let (cfg1, _) = mk_cfg_regs();
         tcn.set_config_reg(cfg1).unwrap();
 
-        // Change reg ptr, then reread the config reg twice.
+        // Change reg ptr, then reread the config reg twice- cfg is still cached from
+        // set_config_reg(), so neither call touches the bus.
         assert_eq!(tcn.set_reg_ptr(0), Ok(()));
         assert_eq!(tcn.config_reg(), Ok(cfg1));
         assert_eq!(tcn.cfg, Some(cfg1));
@@ -1099,15 +1635,16 @@ mod tests {
                 I2cTransaction::write(0x48, vec![1, 0b10000101]),
                 // Cache value reset on write error.
                 I2cTransaction::write(0x48, vec![1, 0b01100000])
-                    .with_error(MockError::Io(ErrorKind::Other)),
+                    .with_error(ErrorKind::Other),
                 // Dummy write to set reg pointer that dies with error.
-                I2cTransaction::write(0x48, vec![0]).with_error(MockError::Io(ErrorKind::Other)),
-                // Read error w/ cache set should be impossible for now.
-                I2cTransaction::write(0x48, vec![1]),
-                I2cTransaction::read(0x48, vec![0b10000101])
-                    .with_error(MockError::Io(ErrorKind::Other)),
-                // Setting the register pointer cache didn't error, so should be skipped.
-                I2cTransaction::read(0x48, vec![0b10000101]),
+                I2cTransaction::write(0x48, vec![0]).with_error(ErrorKind::Other),
+                // The atomic write_read can't tell us whether the pointer write half landed
+                // before the read half failed, so the register pointer cache is flushed even
+                // though only the read could plausibly be at fault.
+                I2cTransaction::write_read(0x48, vec![1], vec![0b10000101])
+                    .with_error(ErrorKind::Other),
+                // So the pointer has to be set again on the next attempt too.
+                I2cTransaction::write_read(0x48, vec![1], vec![0b10000101]),
                 I2cTransaction::write(0x48, vec![1, 0b01100000]),
                 // Cache behavior back to normal- no read here.
             ],
@@ -1119,16 +1656,16 @@ mod tests {
 
         assert_eq!(
             tcn.set_config_reg(cfg1),
-            Err(Tcn75aError::WriteError(MockError::Io(ErrorKind::Other)))
+            Err(Tcn75aError::WriteError(ErrorKind::Other.into()))
         );
         assert_eq!(tcn.cfg, None);
         assert_eq!(
             tcn.set_reg_ptr(0),
-            Err(Tcn75aError::RegPtrError(MockError::Io(ErrorKind::Other)))
+            Err(Tcn75aError::RegPtrError(ErrorKind::Other.into()))
         );
         assert_eq!(
             tcn.config_reg(),
-            Err(Tcn75aError::ReadError(MockError::Io(ErrorKind::Other)))
+            Err(Tcn75aError::ReadError(ErrorKind::Other.into()))
         );
         assert_eq!(tcn.config_reg(), Ok(cfg2));
         assert_eq!(tcn.set_config_reg(cfg1), Ok(()));
@@ -1142,12 +1679,10 @@ mod tests {
                 I2cTransaction::write(0x48, vec![1, 0b10000101]),
                 // Cache value reset on write error.
                 I2cTransaction::write(0x48, vec![1, 0b01100000])
-                    .with_error(MockError::Io(ErrorKind::Other)),
-                I2cTransaction::write(0x48, vec![1]),
-                I2cTransaction::read(0x48, vec![0b10000101]),
+                    .with_error(ErrorKind::Other),
+                I2cTransaction::write_read(0x48, vec![1], vec![0b10000101]),
                 I2cTransaction::write(0x48, vec![1, 0b01100000]),
-                // Cache behavior back to normal.
-                I2cTransaction::read(0x48, vec![0b01100000]),
+                // Cache behavior back to normal- no I2C needed, config_reg() reads from cache.
             ],
             0x48,
         );
@@ -1167,10 +1702,8 @@ mod tests {
             &[
                 I2cTransaction::write(0x48, vec![2, 0x5a, 0x00]),
                 I2cTransaction::write(0x48, vec![3, 0x5f, 0x00]),
-                I2cTransaction::write(0x48, vec![2]),
-                I2cTransaction::read(0x48, vec![0x5a, 0x00]),
-                I2cTransaction::write(0x48, vec![3]),
-                I2cTransaction::read(0x48, vec![0x5f, 0x00]),
+                I2cTransaction::write_read(0x48, vec![2], vec![0x5a, 0x00]),
+                I2cTransaction::write_read(0x48, vec![3], vec![0x5f, 0x00]),
             ],
             0x48,
         );
@@ -1189,10 +1722,8 @@ mod tests {
     fn read_limits_err() {
         let mut tcn = mk_tcn75a(
             &[
-                I2cTransaction::write(0x48, vec![2]),
-                I2cTransaction::read(0x48, vec![0x5a, 0xc0]),
-                I2cTransaction::write(0x48, vec![3]),
-                I2cTransaction::read(0x48, vec![0x5f, 0x00]),
+                I2cTransaction::write_read(0x48, vec![2], vec![0x5a, 0xc0]),
+                I2cTransaction::write_read(0x48, vec![3], vec![0x5f, 0x00]),
             ],
             0x48,
         );
@@ -1212,24 +1743,187 @@ mod tests {
             &[
                 I2cTransaction::write(0x48, vec![2, 0x5a, 0x00]),
                 I2cTransaction::write(0x48, vec![3, 0x5f, 0x00])
-                    .with_error(MockError::Io(ErrorKind::Other)),
-                I2cTransaction::write(0x48, vec![2]),
-                I2cTransaction::read(0x48, vec![0x5a, 0x00]),
-                I2cTransaction::write(0x48, vec![3]),
-                // Technically undefined value- don't actually care what the value is.
-                // Use 0x5f/95 as a placeholder.
-                I2cTransaction::read(0x48, vec![0x5f, 0x00]),
+                    .with_error(ErrorKind::Other),
+                // Rollback: force the Limit-Set register to 127.5, the largest valid Q8.1 value.
+                I2cTransaction::write(0x48, vec![3, 0x7f, 0x80]),
+                I2cTransaction::write_read(0x48, vec![2], vec![0x5a, 0x00]),
+                I2cTransaction::write_read(0x48, vec![3], vec![0x7f, 0x80]),
             ],
             0x48,
         );
 
         assert_eq!(
             tcn.set_limits((fixed!(90.0: I8F8), fixed!(95.0: I8F8)).try_into().unwrap()),
-            Err(Tcn75aError::WriteError(MockError::Io(ErrorKind::Other)))
+            Err(Tcn75aError::PartialUpdate {
+                written: RegId::Hysteresis,
+                source: ErrorKind::Other
+            })
         );
         assert_eq!(
             tcn.limits().unwrap().try_into(),
-            Ok((fixed!(90.0: I8F8), fixed!(95.0: I8F8)))
+            Ok((fixed!(90.0: I8F8), fixed!(127.5: I8F8)))
         );
     }
+
+    #[test]
+    fn shutdown_then_wake() {
+        let mut tcn = mk_tcn75a(
+            &[
+                // shutdown(): cfg cache empty, so config_reg() reads first.
+                I2cTransaction::write_read(0x48, vec![1], vec![0x00]),
+                I2cTransaction::write(0x48, vec![1, 0b0000_0001]),
+                // wake(): cfg cache already holds SHUTDOWN set, so no read is needed.
+                I2cTransaction::write(0x48, vec![1, 0b0000_0000]),
+            ],
+            0x48,
+        );
+
+        assert_eq!(tcn.shutdown(), Ok(()));
+        assert_eq!(tcn.config_reg().unwrap().get_shutdown(), Shutdown::Enable);
+
+        assert_eq!(tcn.wake(), Ok(()));
+        assert_eq!(tcn.config_reg().unwrap().get_shutdown(), Shutdown::Disable);
+    }
+
+    #[test]
+    fn snapshot_then_restore() {
+        let mut tcn = mk_tcn75a(
+            &[
+                // snapshot(): both caches cold, so config_reg() and limits() each read.
+                I2cTransaction::write_read(0x48, vec![1], vec![0b01100000]),
+                I2cTransaction::write_read(0x48, vec![2], vec![0x0a, 0x00]),
+                I2cTransaction::write_read(0x48, vec![3], vec![0x14, 0x00]),
+            ],
+            0x48,
+        );
+
+        let (cfg1, _) = mk_cfg_regs();
+        let limits: Limits = (fixed!(10.0: I8F8), fixed!(20.0: I8F8)).try_into().unwrap();
+
+        let state = tcn.snapshot().unwrap();
+        assert_eq!(state, Tcn75aState { cfg: cfg1, limits });
+
+        // Simulate handing the bus off and reattaching after a power cycle: a fresh Tcn75a with
+        // cold caches, restored from the earlier snapshot.
+        let mut tcn = mk_tcn75a(
+            &[
+                I2cTransaction::write(0x48, vec![1, 0b01100000]),
+                I2cTransaction::write(0x48, vec![2, 0x0a, 0x00]),
+                I2cTransaction::write(0x48, vec![3, 0x14, 0x00]),
+                // limits() isn't cached, so reading the pair back touches the bus again.
+                I2cTransaction::write_read(0x48, vec![2], vec![0x0a, 0x00]),
+                I2cTransaction::write_read(0x48, vec![3], vec![0x14, 0x00]),
+            ],
+            0x48,
+        );
+
+        assert_eq!(tcn.restore(&state), Ok(()));
+        // restore() reprograms via set_config_reg()/set_limits(); set_config_reg() primes the
+        // config cache, so config_reg() needs no further I2C transaction, while limits() re-reads
+        // both registers.
+        assert_eq!(tcn.config_reg(), Ok(cfg1));
+        assert_eq!(tcn.limits(), Ok(limits));
+    }
+
+    #[test]
+    fn one_shot_conversion() {
+        use embedded_hal::delay::DelayNs;
+
+        struct RecordingDelay {
+            ms: Option<u32>,
+        }
+
+        impl DelayNs for RecordingDelay {
+            fn delay_ns(&mut self, ns: u32) {
+                unimplemented!("test only drives one_shot()'s delay_ms call, ns={ns}")
+            }
+
+            fn delay_ms(&mut self, ms: u32) {
+                self.ms = Some(ms);
+            }
+        }
+
+        let mut tcn = mk_tcn75a(
+            &[
+                // cfg cache empty: config_reg() reads the default (Bits9, SHUTDOWN/ONE-SHOT
+                // both clear) before one_shot() flips its bits.
+                I2cTransaction::write_read(0x48, vec![1], vec![0x00]),
+                I2cTransaction::write(0x48, vec![1, 0b1000_0001]),
+                // Conversion done- read back the Ambient Temperature Register.
+                I2cTransaction::write_read(0x48, vec![0], vec![0x19, 0x00]),
+            ],
+            0x48,
+        );
+
+        let mut delay = RecordingDelay { ms: None };
+        let temp = tcn.one_shot(&mut delay);
+
+        assert!(temp.is_ok());
+        let temp = temp.unwrap();
+        assert_eq!(temp.resolution(), Resolution::Bits9);
+        assert_eq!(I8F8::from(temp), fixed!(25.0: I8F8));
+        // Bits9 conversion time.
+        assert_eq!(delay.ms, Some(30));
+
+        // The TCN75A cleared ONE-SHOT on its own; the cache should agree without another read.
+        assert_eq!(tcn.config_reg().unwrap().get_one_shot(), OneShot::Disabled);
+        assert_eq!(tcn.config_reg().unwrap().get_shutdown(), Shutdown::Enable);
+    }
+
+    #[test]
+    fn temperature_uncached_single_transaction() {
+        let mut tcn = mk_tcn75a(
+            &[I2cTransaction::write_read(
+                0x48,
+                vec![0x00],
+                vec![0x7f, 0x80],
+            )],
+            0x48,
+        );
+
+        // No prior `set_reg_ptr`/`temperature` call- the pointer write and data read happen in
+        // one transaction, so there's no cache to warm up first.
+        let temp = tcn.temperature_uncached();
+        assert!(temp.is_ok());
+        assert_eq!(
+            I8F8::from(temp.unwrap()),
+            I8F8::from_bits(((127 << 4) + 8) << 4)
+        );
+        // The register pointer cache is untouched by the uncached path.
+        assert_eq!(tcn.reg, None);
+    }
+
+    #[test]
+    fn config_reg_uncached_bypasses_cache() {
+        let mut tcn = mk_tcn75a(
+            &[
+                I2cTransaction::write_read(0x48, vec![0x01], vec![0b01100000]),
+                // Calling again performs another transaction- there's no cache to hit.
+                I2cTransaction::write_read(0x48, vec![0x01], vec![0b01100000]),
+            ],
+            0x48,
+        );
+        let (cfg1, _) = mk_cfg_regs();
+
+        assert_eq!(tcn.config_reg_uncached(), Ok(cfg1));
+        assert_eq!(tcn.cfg, None);
+        assert_eq!(tcn.config_reg_uncached(), Ok(cfg1));
+    }
+
+    #[test]
+    fn limits_uncached_single_transaction() {
+        let mut tcn = mk_tcn75a(
+            &[
+                I2cTransaction::write_read(0x48, vec![0x02], vec![0x5a, 0x00]),
+                I2cTransaction::write_read(0x48, vec![0x03], vec![0x5f, 0x00]),
+            ],
+            0x48,
+        );
+
+        assert_eq!(
+            tcn.limits_uncached(),
+            Ok((fixed!(90.0: I8F8), fixed!(95.0: I8F8)).try_into().unwrap())
+        );
+        assert_eq!(tcn.reg, None);
+    }
 }
@@ -0,0 +1,25 @@
+use crate::{ConfigReg, Limits};
+
+/** A snapshot of a TCN75A's Sensor Configuration, Hysteresis, and Limit-Set registers, captured
+by [`snapshot`] and reprogrammed in one shot by [`restore`].
+
+This lets an application checkpoint a sensor's full configuration (to non-volatile storage, for
+instance) and deterministically re-establish it later- after [`free`]/[`Tcn75a::new`], or after a
+power cycle- instead of rebuilding each register by hand.
+
+With the `serde` feature enabled, [`Tcn75aState`] implements [`Serialize`]/[`Deserialize`].
+
+[`snapshot`]: ./struct.Tcn75a.html#method.snapshot
+[`restore`]: ./struct.Tcn75a.html#method.restore
+[`free`]: ./struct.Tcn75a.html#method.free
+[`Tcn75a::new`]: ./struct.Tcn75a.html#method.new
+[`Tcn75aState`]: ./struct.Tcn75aState.html
+[`Serialize`]: ../serde/trait.Serialize.html
+[`Deserialize`]: ../serde/trait.Deserialize.html
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tcn75aState {
+    pub(crate) cfg: ConfigReg,
+    pub(crate) limits: Limits,
+}
@@ -1,5 +1,7 @@
 use fixed::types::I8F8;
 
+use crate::Resolution;
+
 /** A struct representing a temperature reading from the TCN75A.
 
 # Internals
@@ -22,9 +24,8 @@ can be used simultaneously with its contained [`I8F8`].
 # cfg_if::cfg_if! {
 # if #[cfg(any(target_os = "linux", target_os = "android"))] {
 # use linux_embedded_hal::I2cdev;
-# use embedded_hal::blocking::i2c::{Read, Write};
-# use tcn75a::{Tcn75a, Tcn75aError, ConfigReg, Resolution};
-# fn main() -> Result<(), Tcn75aError<I2cdev, I2cdev>> {
+# use tcn75a::{Tcn75a, Error, ConfigReg, Resolution};
+# fn main() -> Result<(), Error<I2cdev>> {
 # let i2c = I2cdev::new("/dev/i2c-1").unwrap();
 # let mut tcn = Tcn75a::new(i2c, 0x48);
 use fixed::types::I8F8;
@@ -58,7 +59,19 @@ if temp0 < baseline {
 [`Copy`]: https://doc.rust-lang.org/nightly/core/marker/trait.Copy.html
 */
 #[derive(Debug, Clone, Copy)]
-pub struct Temperature(pub(crate) I8F8);
+pub struct Temperature(pub(crate) I8F8, pub(crate) Resolution);
+
+impl Temperature {
+    /** The [`Resolution`] in effect when this measurement was taken, i.e. the real precision of
+    the contained [`I8F8`] value (bits below that precision are always zero).
+
+    [`Resolution`]: ./enum.Resolution.html
+    [`I8F8`]: ../fixed/types/type.I8F8.html
+    */
+    pub fn resolution(&self) -> Resolution {
+        self.1
+    }
+}
 
 impl From<Temperature> for I8F8 {
     fn from(temp: Temperature) -> Self {
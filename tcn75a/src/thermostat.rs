@@ -0,0 +1,314 @@
+/*! High-level thermal-watchdog helper over [`Tcn75a`]'s [`Limits`]/[`ConfigReg`]/[`CompInt`]/
+[`AlertPolarity`] knobs and the TCN75A's ALERT pin.
+
+Configuring an over-temperature alarm on a bare [`Tcn75a`] means hand-assembling a [`ConfigReg`]
+(comparator vs. interrupt mode, polarity, fault queue) *and* a [`Limits`] pair, then remembering
+how to interpret the ALERT pin correctly for whichever polarity and mode you picked. [`Thermostat`]
+packages that into a single constructor and a single status check, the way the `lm75`/NuttX
+drivers do.
+
+[`Tcn75a`]: ../struct.Tcn75a.html
+[`Limits`]: ../struct.Limits.html
+[`ConfigReg`]: ../struct.ConfigReg.html
+[`CompInt`]: ../enum.CompInt.html
+[`AlertPolarity`]: ../enum.AlertPolarity.html
+[`Thermostat`]: ./struct.Thermostat.html
+*/
+use core::convert::TryFrom;
+use core::fmt;
+
+use embedded_hal::digital::InputPin;
+use embedded_hal::i2c::I2c;
+use fixed::types::I8F8;
+
+use crate::{AlertPolarity, CompInt, Error, FaultQueue, LimitError, Limits, Tcn75a, Tcn75aError};
+
+/** Error type for [`Thermostat`] operations.
+
+Wraps either a [`Tcn75a`] bus error (same as [`Error<T>`]) or an error from the alert [`InputPin`].
+
+[`Thermostat`]: ./struct.Thermostat.html
+[`Tcn75a`]: ../struct.Tcn75a.html
+[`Error<T>`]: ../type.Error.html
+[`InputPin`]: ../embedded_hal/digital/trait.InputPin.html
+*/
+pub enum ThermostatError<T, P>
+where
+    T: I2c,
+    P: InputPin,
+{
+    /** An I2C transaction against the TCN75A failed. Contains the underlying [`Error<T>`].
+
+    [`Error<T>`]: ../type.Error.html
+    */
+    Bus(Error<T>),
+    /** Reading the alert [`InputPin`] failed. Contains the pin's error type.
+
+    [`InputPin`]: ../embedded_hal/digital/trait.InputPin.html
+    */
+    AlertPin(P::Error),
+}
+
+impl<T, P> fmt::Debug for ThermostatError<T, P>
+where
+    T: I2c,
+    P: InputPin,
+    Error<T>: fmt::Debug,
+    P::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThermostatError::Bus(e) => f.debug_tuple("Bus").field(e).finish(),
+            ThermostatError::AlertPin(e) => f.debug_tuple("AlertPin").field(e).finish(),
+        }
+    }
+}
+
+impl<T, P> PartialEq<Self> for ThermostatError<T, P>
+where
+    T: I2c,
+    P: InputPin,
+    Error<T>: PartialEq<Error<T>>,
+    P::Error: PartialEq<P::Error>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ThermostatError::Bus(s), ThermostatError::Bus(o)) => s == o,
+            (ThermostatError::AlertPin(s), ThermostatError::AlertPin(o)) => s == o,
+            _ => false,
+        }
+    }
+}
+
+/** A thermal watchdog built on top of [`Tcn75a`]'s comparator/interrupt ALERT output.
+
+Construction programs the Hysteresis/Limit-Set registers and the comparator-vs-interrupt mode,
+alert polarity, and fault queue depth in one [`set_config_reg`]/[`set_limits`] sequence.
+[`is_over_temperature`] then tells the caller whether the part is currently signalling
+over-temperature, given the state of a host [`InputPin`] wired to the ALERT pin- correctly
+accounting for the configured polarity, and, in interrupt mode, clearing the latched alert by
+reading the TCN75A back afterwards (the TCN75A clears ALERT on any register read in interrupt
+mode, unlike comparator mode where ALERT tracks the limits directly).
+
+[`Tcn75a`]: ../struct.Tcn75a.html
+[`set_config_reg`]: ../struct.Tcn75a.html#method.set_config_reg
+[`set_limits`]: ../struct.Tcn75a.html#method.set_limits
+[`is_over_temperature`]: ./struct.Thermostat.html#method.is_over_temperature
+[`InputPin`]: ../embedded_hal/digital/trait.InputPin.html
+*/
+pub struct Thermostat<T>
+where
+    T: I2c,
+{
+    tcn: Tcn75a<T>,
+    mode: CompInt,
+    polarity: AlertPolarity,
+}
+
+impl<T> Thermostat<T>
+where
+    T: I2c,
+{
+    /** Programs `tcn` with the given limits, mode, polarity, and fault queue depth, and wraps it
+    as a [`Thermostat`].
+
+    `hysteresis` and `high` are validated as a [`Limits`] pair (`hysteresis` must be the lower,
+    `high` the upper bound, both representable at [`Q8.1`] precision) before anything is written
+    to the bus.
+
+    # Errors
+
+    * [`Tcn75aError::LimitError`]: `hysteresis`/`high` don't form a valid [`Limits`] pair. No I2C
+      transaction occurs.
+    * Otherwise, the same errors as [`Tcn75a::set_config_reg`] and [`Tcn75a::set_limits`].
+
+    [`Thermostat`]: ./struct.Thermostat.html
+    [`Limits`]: ../struct.Limits.html
+    [`Q8.1`]: https://en.wikipedia.org/wiki/Q_(number_format)
+    [`Tcn75aError::LimitError`]: ../enum.Tcn75aError.html#variant.LimitError
+    [`Tcn75a::set_config_reg`]: ../struct.Tcn75a.html#method.set_config_reg
+    [`Tcn75a::set_limits`]: ../struct.Tcn75a.html#method.set_limits
+    */
+    pub fn new(
+        mut tcn: Tcn75a<T>,
+        hysteresis: I8F8,
+        high: I8F8,
+        mode: CompInt,
+        polarity: AlertPolarity,
+        fault_queue: FaultQueue,
+    ) -> Result<Self, Error<T>> {
+        let limits = Limits::try_from((hysteresis, high)).map_err(|reason| {
+            Tcn75aError::LimitError {
+                reason,
+                values: (hysteresis, high),
+            }
+        })?;
+
+        let mut cfg = tcn.config_reg()?;
+        cfg.set_comp_int(mode);
+        cfg.set_alert_polarity(polarity);
+        cfg.set_fault_queue(fault_queue);
+        tcn.set_config_reg(cfg)?;
+        tcn.set_limits(limits)?;
+
+        Ok(Thermostat { tcn, mode, polarity })
+    }
+
+    /** Tells the caller whether the TCN75A is currently signalling over-temperature, given the
+    state of a host [`InputPin`] wired to its ALERT pin.
+
+    The raw pin level is interpreted according to the configured [`AlertPolarity`]. In
+    [`CompInt::Interrupt`] mode, a detected alert is latched by the TCN75A until a register is
+    read; this function clears it by re-reading the Sensor Configuration Register (bypassing the
+    cache) before returning, so the next call observes a fresh state rather than a stale latch.
+    In [`CompInt::Comparator`] mode, ALERT tracks the limits directly and nothing further is done.
+
+    # Errors
+
+    * [`ThermostatError::AlertPin`]: Reading `alert_pin` failed.
+    * [`ThermostatError::Bus`]: In interrupt mode, clearing the latched alert failed.
+
+    [`InputPin`]: ../embedded_hal/digital/trait.InputPin.html
+    [`AlertPolarity`]: ../enum.AlertPolarity.html
+    [`CompInt::Interrupt`]: ../enum.CompInt.html#variant.Interrupt
+    [`CompInt::Comparator`]: ../enum.CompInt.html#variant.Comparator
+    [`ThermostatError::AlertPin`]: ./enum.ThermostatError.html#variant.AlertPin
+    [`ThermostatError::Bus`]: ./enum.ThermostatError.html#variant.Bus
+    */
+    pub fn is_over_temperature<P>(
+        &mut self,
+        alert_pin: &mut P,
+    ) -> Result<bool, ThermostatError<T, P>>
+    where
+        P: InputPin,
+    {
+        let asserted = alert_pin.is_high().map_err(ThermostatError::AlertPin)?;
+        let active = match self.polarity {
+            AlertPolarity::ActiveHigh => asserted,
+            AlertPolarity::ActiveLow => !asserted,
+        };
+
+        if self.mode == CompInt::Interrupt {
+            // Force past the cache so this actually touches the bus and clears the latch.
+            self.tcn.cfg = None;
+            self.tcn.config_reg().map_err(ThermostatError::Bus)?;
+        }
+
+        Ok(active)
+    }
+
+    /// Release the wrapped [`Tcn75a`].
+    ///
+    /// [`Tcn75a`]: ../struct.Tcn75a.html
+    pub fn free(self) -> Tcn75a<T> {
+        self.tcn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use embedded_hal_mock::eh1::digital::{
+        Mock as PinMock, State as PinState, Transaction as PinTransaction,
+    };
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+    use fixed_macro::fixed;
+
+    #[test]
+    fn new_programs_config_and_limits() {
+        let i2c = I2cMock::new(&[
+            I2cTransaction::write_read(0x48, std::vec![1], std::vec![0x00]),
+            I2cTransaction::write(0x48, std::vec![1, 0x04]),
+            I2cTransaction::write(0x48, std::vec![2, 0x19, 0x00]),
+            I2cTransaction::write(0x48, std::vec![3, 0x1e, 0x00]),
+        ]);
+        let tcn = Tcn75a::new(i2c, 0x48);
+
+        assert!(Thermostat::new(
+            tcn,
+            fixed!(25.0: I8F8),
+            fixed!(30.0: I8F8),
+            CompInt::Comparator,
+            AlertPolarity::ActiveHigh,
+            FaultQueue::One,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn new_rejects_invalid_limits() {
+        // Hysteresis above high- `Thermostat::new` must fail before touching the bus.
+        let i2c = I2cMock::new(&[]);
+        let tcn = Tcn75a::new(i2c, 0x48);
+
+        assert_eq!(
+            Thermostat::new(
+                tcn,
+                fixed!(30.0: I8F8),
+                fixed!(25.0: I8F8),
+                CompInt::Comparator,
+                AlertPolarity::ActiveHigh,
+                FaultQueue::One,
+            )
+            .unwrap_err(),
+            Tcn75aError::LimitError {
+                reason: LimitError::LowExceedsHigh,
+                values: (fixed!(30.0: I8F8), fixed!(25.0: I8F8)),
+            }
+        );
+    }
+
+    #[test]
+    fn comparator_mode_reads_pin_only() {
+        let i2c = I2cMock::new(&[
+            I2cTransaction::write_read(0x48, std::vec![1], std::vec![0x00]),
+            I2cTransaction::write(0x48, std::vec![1, 0x04]),
+            I2cTransaction::write(0x48, std::vec![2, 0x19, 0x00]),
+            I2cTransaction::write(0x48, std::vec![3, 0x1e, 0x00]),
+        ]);
+        let tcn = Tcn75a::new(i2c, 0x48);
+        let mut thermostat = Thermostat::new(
+            tcn,
+            fixed!(25.0: I8F8),
+            fixed!(30.0: I8F8),
+            CompInt::Comparator,
+            AlertPolarity::ActiveHigh,
+            FaultQueue::One,
+        )
+        .unwrap();
+
+        // No further I2C transactions expected- comparator mode needs none.
+        let mut pin = PinMock::new(&[PinTransaction::get(PinState::High)]);
+        assert_eq!(thermostat.is_over_temperature(&mut pin), Ok(true));
+    }
+
+    #[test]
+    fn interrupt_mode_clears_latch_after_check() {
+        let i2c = I2cMock::new(&[
+            // new(): default config read, then comp_int=Interrupt (bit1) + alert_polarity=
+            // ActiveLow (default, no change) written back, then limits.
+            I2cTransaction::write_read(0x48, std::vec![1], std::vec![0x00]),
+            I2cTransaction::write(0x48, std::vec![1, 0b0000_0010]),
+            I2cTransaction::write(0x48, std::vec![2, 0x19, 0x00]),
+            I2cTransaction::write(0x48, std::vec![3, 0x1e, 0x00]),
+            // is_over_temperature(): interrupt mode forces a fresh config read to clear ALERT.
+            I2cTransaction::write_read(0x48, std::vec![1], std::vec![0b0000_0010]),
+        ]);
+        let tcn = Tcn75a::new(i2c, 0x48);
+        let mut thermostat = Thermostat::new(
+            tcn,
+            fixed!(25.0: I8F8),
+            fixed!(30.0: I8F8),
+            CompInt::Interrupt,
+            AlertPolarity::ActiveLow,
+            FaultQueue::One,
+        )
+        .unwrap();
+
+        // Active-low: a deasserted (high) pin means alert is NOT asserted.
+        let mut pin = PinMock::new(&[PinTransaction::get(PinState::High)]);
+        assert_eq!(thermostat.is_over_temperature(&mut pin), Ok(false));
+    }
+}
@@ -0,0 +1,121 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/** Runtime parameters for the integration-test HAL, read from a `key=value` config file
+and/or environment variables- the same `key=value` convention the `i2c-server` crate's own
+`Config` and [`tcn75a::ConfigReg::from_config_str`] use, so a test rig's board/bus selection
+doesn't need its own bespoke format.
+
+Every field is optional; [`TestConfig::resolve`] falls back to `setup()`'s old hardcoded
+defaults (`/dev/i2c-1` at `0x48`) for anything left unset, so existing CI jobs keep working
+without a config file.
+
+[`tcn75a::ConfigReg::from_config_str`]: ../../tcn75a/struct.ConfigReg.html#method.from_config_str
+*/
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TestConfig {
+    /// I2C bus: a device path (e.g. `/dev/i2c-1`) on Linux, or the literal `simulated` to force
+    /// a simulated HAL even where a real Linux bus is available.
+    pub bus: Option<String>,
+    /// TCN75A 7-bit I2C address.
+    pub addr: Option<u8>,
+    /// Reserved for a future `SampleBuf`-backed harness; parsed here for forward compatibility
+    /// with the `i2c-server` crate's own config keys, but this crate's tests don't consume it.
+    pub sample_rate: Option<u8>,
+    /// Reserved for a future `SampleBuf`-backed harness; see [`sample_rate`](TestConfig::sample_rate).
+    pub capacity: Option<usize>,
+}
+
+impl TestConfig {
+    /// Parses a `key=value`-per-line config file. Unknown keys and blank/`#`-commented lines
+    /// are ignored. A malformed value is silently dropped, leaving the field unset, rather than
+    /// failing the whole file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<TestConfig> {
+        let contents = fs::read_to_string(path)?;
+        let mut cfg = TestConfig::default();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = match parts.next() {
+                Some(v) => v.trim(),
+                None => continue,
+            };
+
+            match key {
+                "bus" => cfg.bus = Some(value.to_string()),
+                "addr" => cfg.addr = u8::from_str_radix(value.trim_start_matches("0x"), 16).ok(),
+                "sample_rate" => cfg.sample_rate = value.parse().ok(),
+                "capacity" => cfg.capacity = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Ok(cfg)
+    }
+
+    /// Reads `TCN75A_TEST_BUS`/`TCN75A_TEST_ADDR`/`TCN75A_TEST_SAMPLE_RATE`/
+    /// `TCN75A_TEST_CAPACITY` from the environment. A malformed value leaves the field unset,
+    /// same as [`from_file`](TestConfig::from_file).
+    pub fn from_env() -> TestConfig {
+        TestConfig {
+            bus: env::var("TCN75A_TEST_BUS").ok(),
+            addr: env::var("TCN75A_TEST_ADDR")
+                .ok()
+                .and_then(|v| u8::from_str_radix(v.trim_start_matches("0x"), 16).ok()),
+            sample_rate: env::var("TCN75A_TEST_SAMPLE_RATE").ok().and_then(|v| v.parse().ok()),
+            capacity: env::var("TCN75A_TEST_CAPACITY").ok().and_then(|v| v.parse().ok()),
+        }
+    }
+
+    /// Overlays `other` on top of `self`, preferring `other`'s values wherever it has one.
+    /// Intended usage is `file_cfg.merge(env_cfg)`, so the environment wins over the file.
+    pub fn merge(self, other: TestConfig) -> TestConfig {
+        TestConfig {
+            bus: other.bus.or(self.bus),
+            addr: other.addr.or(self.addr),
+            sample_rate: other.sample_rate.or(self.sample_rate),
+            capacity: other.capacity.or(self.capacity),
+        }
+    }
+
+    /** Builds the effective [`TestConfig`] for this test run: loads `TCN75A_TEST_CONFIG`'s
+    config file, if that environment variable is set, then overlays the rest of the
+    `TCN75A_TEST_*` environment variables on top.
+
+    Returns `Err` only if `TCN75A_TEST_CONFIG` names a file that can't be read- an unset
+    variable just means "no config file", not an error.
+    */
+    pub fn resolve() -> io::Result<TestConfig> {
+        let file_cfg = match env::var("TCN75A_TEST_CONFIG") {
+            Ok(path) => TestConfig::from_file(path)?,
+            Err(_) => TestConfig::default(),
+        };
+
+        Ok(file_cfg.merge(TestConfig::from_env()))
+    }
+
+    /// The I2C bus device path/selector to use, falling back to `setup()`'s old hardcoded
+    /// `/dev/i2c-1` if unset.
+    pub fn bus_or_default(&self) -> &str {
+        self.bus.as_deref().unwrap_or("/dev/i2c-1")
+    }
+
+    /// The TCN75A address to use, falling back to `setup()`'s old hardcoded `0x48` if unset.
+    pub fn addr_or_default(&self) -> u8 {
+        self.addr.unwrap_or(0x48)
+    }
+
+    /// Whether `bus = simulated` was configured, i.e. `setup()` should build a `SimulatedHal`
+    /// even on a platform with a real I2C bus backend.
+    pub fn wants_simulated(&self) -> bool {
+        self.bus.as_deref() == Some("simulated")
+    }
+}
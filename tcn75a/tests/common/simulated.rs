@@ -0,0 +1,170 @@
+use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation};
+use fixed::types::I8F8;
+use tcn75a::{ConfigReg, Resolution};
+
+const REG_TEMP: u8 = 0x00;
+const REG_CONFIG: u8 = 0x01;
+const REG_HYST: u8 = 0x02;
+const REG_LIMIT_SET: u8 = 0x03;
+
+/** Where [`SimulatedHal`] gets the "true" ambient temperature it reports through the Temperature
+register, before it gets masked down to the currently configured [`Resolution`].
+*/
+pub enum TempSource {
+    /// Always reports the same value.
+    Fixed(I8F8),
+    /// Starts at `start` and moves by `step` (which may be negative) on every Temperature
+    /// register read, the way a slowly drifting real sensor would.
+    Ramp { start: I8F8, step: I8F8 },
+    /// Calls the closure for a fresh reading on every Temperature register read.
+    Closure(Box<dyn FnMut() -> I8F8>),
+}
+
+impl TempSource {
+    fn next(&mut self) -> I8F8 {
+        match self {
+            TempSource::Fixed(v) => *v,
+            TempSource::Ramp { start, step } => {
+                let v = *start;
+                *start += *step;
+                v
+            }
+            TempSource::Closure(f) => f(),
+        }
+    }
+}
+
+/** A software model of a TCN75A's register file, standing in for [`linux_embedded_hal::I2cdev`]
+on platforms (or CI runs) with no real sensor wired up.
+
+[`SimulatedHal`] tracks a register pointer and the Sensor Configuration/Hysteresis/Limit-Set
+registers exactly as the real TCN75A would- honoring `write`/`write_read`/`transaction` against
+whichever register the pointer currently names- and synthesizes Temperature register reads from
+an injectable [`TempSource`], masked down to the currently configured [`Resolution`] the same way
+the real silicon rounds off unused low bits. That's enough for [`Tcn75a`]'s
+`set_config_reg`/`temperature` round-trip to behave identically to hardware, so driver logic gets
+real coverage on every platform, not just Linux.
+
+[`Tcn75a`]: ../../tcn75a/struct.Tcn75a.html
+*/
+pub struct SimulatedHal {
+    address: u8,
+    reg_ptr: u8,
+    cfg: ConfigReg,
+    hyst: i16,
+    limit_set: i16,
+    source: TempSource,
+}
+
+impl SimulatedHal {
+    pub fn new(address: u8, source: TempSource) -> Self {
+        SimulatedHal {
+            address,
+            reg_ptr: REG_TEMP,
+            cfg: ConfigReg::new(),
+            hyst: 0,
+            limit_set: 0,
+            source,
+        }
+    }
+
+    /** Mirrors `Resolution::out_of_range_mask`, which is `pub(crate)` to the `tcn75a` crate and
+    so isn't reachable from this integration-test binary- the mask of Temperature register bits
+    that must read zero at a given resolution. */
+    fn resolution_mask(resolution: Resolution) -> i16 {
+        match resolution {
+            Resolution::Bits9 => 0x007F,
+            Resolution::Bits10 => 0x003F,
+            Resolution::Bits11 => 0x001F,
+            Resolution::Bits12 => 0x000F,
+        }
+    }
+
+    fn temp_register(&mut self) -> [u8; 2] {
+        let raw = self.source.next().to_bits();
+        let masked = raw & !Self::resolution_mask(self.cfg.get_resolution());
+        masked.to_be_bytes()
+    }
+
+    fn reg_contents(&mut self, reg: u8) -> Result<Vec<u8>, ErrorKind> {
+        match reg {
+            REG_TEMP => Ok(self.temp_register().to_vec()),
+            REG_CONFIG => Ok(self.cfg.into_bytes().to_vec()),
+            REG_HYST => Ok(self.hyst.to_be_bytes().to_vec()),
+            REG_LIMIT_SET => Ok(self.limit_set.to_be_bytes().to_vec()),
+            _ => Err(ErrorKind::Other),
+        }
+    }
+
+    fn set_reg_contents(&mut self, reg: u8, data: &[u8]) {
+        match reg {
+            REG_CONFIG if !data.is_empty() => self.cfg = ConfigReg::from_bytes([data[0]]),
+            REG_HYST if data.len() >= 2 => self.hyst = i16::from_be_bytes([data[0], data[1]]),
+            REG_LIMIT_SET if data.len() >= 2 => {
+                self.limit_set = i16::from_be_bytes([data[0], data[1]])
+            }
+            // The Temperature register is read-only on real hardware; writes to it are ignored.
+            _ => {}
+        }
+    }
+
+    fn check_address(&self, address: u8) -> Result<(), ErrorKind> {
+        if address == self.address {
+            Ok(())
+        } else {
+            Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+        }
+    }
+}
+
+impl ErrorType for SimulatedHal {
+    type Error = ErrorKind;
+}
+
+impl I2c for SimulatedHal {
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_address(address)?;
+
+        let contents = self.reg_contents(self.reg_ptr)?;
+        if contents.len() != buffer.len() {
+            return Err(ErrorKind::Other);
+        }
+        buffer.copy_from_slice(&contents);
+        Ok(())
+    }
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_address(address)?;
+
+        let (&reg, data) = bytes.split_first().ok_or(ErrorKind::Other)?;
+        self.reg_ptr = reg;
+        self.set_reg_contents(reg, data);
+        Ok(())
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.write(address, bytes)?;
+        self.read(address, buffer)
+    }
+
+    fn transaction<'a>(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        // Mirrors `BlockingI2cdev::transaction`: no atomicity to preserve here either, since
+        // nothing else can touch this `SimulatedHal` between operations.
+        for op in operations {
+            match op {
+                Operation::Read(buf) => self.read(address, buf)?,
+                Operation::Write(buf) => self.write(address, buf)?,
+            }
+        }
+        Ok(())
+    }
+}
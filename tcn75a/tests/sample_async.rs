@@ -0,0 +1,45 @@
+#![cfg(feature = "async")]
+
+use embedded_hal_async::i2c::I2c;
+use fixed::types::I8F8;
+use tcn75a::asynch::Tcn75aAsync;
+use tcn75a::{ConfigReg, Resolution};
+
+mod common;
+
+#[test]
+fn test_sample_async() {
+    let (hal, addr) = common::setup_async().expect("failed to set up test HAL");
+    let tcn = Tcn75aAsync::new(hal, addr);
+
+    common::block_on(sample(tcn));
+}
+
+async fn sample<T>(mut tcn: Tcn75aAsync<T>)
+where
+    T: I2c,
+{
+    let mut cfg = ConfigReg::new();
+    cfg.set_resolution(Resolution::Bits9);
+    assert!(tcn.set_config_reg(cfg).await.is_ok());
+
+    // This test only works if you're in a room with temperature > 0C!
+    let temp9: I8F8 = match tcn.temperature().await {
+        Ok(t) => {
+            assert!(I8F8::from(t) > I8F8::from_num(0));
+            t.into()
+        }
+        _ => panic!("Could not get temperature reading"),
+    };
+
+    cfg.set_resolution(Resolution::Bits12);
+    assert!(tcn.set_config_reg(cfg).await.is_ok());
+
+    // Check that 12-bit temp is within 0.5C of 9-bit temp.
+    let temp12: I8F8 = match tcn.temperature().await {
+        Ok(t) => t.into(),
+        _ => panic!("Could not get temperature reading"),
+    };
+    let one_half = I8F8::from_num(1) / 2;
+    assert!((temp9 + one_half) >= temp12 && (temp9 - one_half) <= temp12);
+}